@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use reqwest::header;
+
+use super::{RobloxApi, RobloxError, User};
+
+mod request_types;
+
+const BLOCKED_USERS_API: &str =
+    "https://accountsettings.roblox.com/v1/users/get-detailed-blocked-users";
+const BLOCK_API: &str = "https://accountsettings.roblox.com/v1/users/{target_id}/block";
+const UNBLOCK_API: &str = "https://accountsettings.roblox.com/v1/users/{target_id}/unblock";
+
+impl RobloxApi {
+    /// Get list of users blocked by the logged in user using <https://accountsettings.roblox.com/v1/users/get-detailed-blocked-users>.
+    pub async fn blocked_users(&self) -> Result<Vec<User>, RobloxError> {
+        let cookie = self.cookie_string().await?;
+
+        let request_result = self
+            .reqwest_client
+            .get(BLOCKED_USERS_API)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::BlockedUsersResponse>(response).await?;
+
+        let users = raw
+            .blocked_users
+            .into_iter()
+            .map(|user| User {
+                user_id: user.user_id,
+                username: user.name,
+                display_name: user.display_name,
+            })
+            .collect();
+
+        Ok(users)
+    }
+
+    /// Get ids of users blocked by the logged in user. Used to filter blocked users out of other responses.
+    pub async fn blocked_user_ids(&self) -> Result<HashSet<u64>, RobloxError> {
+        let blocked_users = self.blocked_users().await?;
+
+        Ok(blocked_users.into_iter().map(|user| user.user_id).collect())
+    }
+
+    /// Checks whether the logged in user has blocked the specified user.
+    pub async fn is_blocked(&self, user_id: u64) -> Result<bool, RobloxError> {
+        let blocked_user_ids = self.blocked_user_ids().await?;
+
+        Ok(blocked_user_ids.contains(&user_id))
+    }
+
+    /// Blocks a user using <https://accountsettings.roblox.com/v1/users/{target_id}/block>.
+    pub async fn block(&self, target_id: u64) -> Result<(), RobloxError> {
+        match self.block_internal(BLOCK_API, target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.block_internal(BLOCK_API, target_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unblocks a user using <https://accountsettings.roblox.com/v1/users/{target_id}/unblock>.
+    pub async fn unblock(&self, target_id: u64) -> Result<(), RobloxError> {
+        match self.block_internal(UNBLOCK_API, target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.block_internal(UNBLOCK_API, target_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use reqwest::header;
+
+    use crate::client::{RobloxApi, RobloxError, XCSRF_HEADER};
+
+    impl RobloxApi {
+        /// Shared implementation of [`RobloxApi::block`] and [`RobloxApi::unblock`].
+        pub(super) async fn block_internal(
+            &self,
+            api: &str,
+            target_id: u64,
+        ) -> Result<(), RobloxError> {
+            let formatted_url = api.replace("{target_id}", &target_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+    }
+}
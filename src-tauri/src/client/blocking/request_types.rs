@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockedUsersResponse {
+    pub blocked_users: Vec<BlockedUserRaw>,
+    pub max_blocked_users: u64,
+    pub total: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BlockedUserRaw {
+    pub user_id: u64,
+    pub name: String,
+    pub display_name: String,
+}
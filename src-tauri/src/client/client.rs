@@ -1,8 +1,10 @@
+use std::{collections::HashMap, time::Instant};
+
 use reqwest::{header::HeaderValue, Client};
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
-use super::RobloxError;
+use super::{friends::MutualFriend, RobloxError};
 
 /// Basic information about the account of the Roblosecurity. Retrieved
 /// from <https://users.roblox.com/v1/users/authenticated>.
@@ -30,6 +32,12 @@ pub struct RobloxApi {
 
     /// A Reqwest HTTP client used to send web requests.
     pub(crate) reqwest_client: reqwest::Client,
+
+    /// Cached results of [`RobloxApi::mutual_friends`], keyed by the target user id.
+    pub(crate) mutual_friends_cache: RwLock<HashMap<u64, (Instant, Vec<MutualFriend>)>>,
+
+    /// Cached headshot urls of the logged in user's friends, used by [`RobloxApi::server_players`].
+    pub(crate) friend_headshots_cache: RwLock<Option<(Instant, HashMap<String, u64>)>>,
 }
 
 impl RobloxApi {
@@ -77,6 +85,8 @@ impl RobloxApi {
             });
 
         *self.cookie_string.write().await = cookie.clone();
+        self.mutual_friends_cache.write().await.clear();
+        *self.friend_headshots_cache.write().await = None;
 
         if cookie.is_some() {
             let result = self.user_information_internal().await;
@@ -7,6 +7,7 @@ mod request_types;
 const OMNI_RECOMMENDATIONS_API: &str = "https://apis.roblox.com/discovery-api/omni-recommendation";
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum TreatmentType {
     /// Carousel of friends
     #[default]
@@ -17,17 +18,29 @@ pub enum TreatmentType {
 
     /// Grid (square cards)
     SortlessGrid,
-}
 
-impl TryFrom<String> for TreatmentType {
-    type Error = RobloxError;
+    /// Treatment type unknown to rsblox, with the raw value sent by Roblox.
+    Unknown(String),
+}
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+impl From<String> for TreatmentType {
+    fn from(value: String) -> Self {
         match value.as_str() {
-            "FriendCarousel" => Ok(Self::FriendCarousel),
-            "Carousel" => Ok(Self::Carousel),
-            "SortlessGrid" => Ok(Self::SortlessGrid),
-            _ => Err(RobloxError::MalformedResponse),
+            "FriendCarousel" => Self::FriendCarousel,
+            "Carousel" => Self::Carousel,
+            "SortlessGrid" => Self::SortlessGrid,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<TreatmentType> for String {
+    fn from(value: TreatmentType) -> Self {
+        match value {
+            TreatmentType::FriendCarousel => "FriendCarousel".to_string(),
+            TreatmentType::Carousel => "Carousel".to_string(),
+            TreatmentType::SortlessGrid => "SortlessGrid".to_string(),
+            TreatmentType::Unknown(raw) => raw,
         }
     }
 }
@@ -152,7 +165,7 @@ mod internal {
                     topic_id: raw_topic.topic_id,
                     topic: raw_topic.topic,
                     subtitle: raw_topic.subtitle,
-                    treatment_type: TreatmentType::try_from(raw_topic.treatment_type)?,
+                    treatment_type: TreatmentType::from(raw_topic.treatment_type),
                     recommendation_list,
                 })
             }
@@ -164,3 +177,39 @@ mod internal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TreatmentType;
+
+    #[test]
+    fn treatment_type_from_known_value() {
+        assert_eq!(
+            TreatmentType::from("SortlessGrid".to_string()),
+            TreatmentType::SortlessGrid
+        );
+    }
+
+    #[test]
+    fn treatment_type_from_unknown_value() {
+        assert_eq!(
+            TreatmentType::from("InterestTile".to_string()),
+            TreatmentType::Unknown("InterestTile".to_string())
+        );
+    }
+
+    #[test]
+    fn treatment_type_round_trips() {
+        for treatment_type in [
+            TreatmentType::Carousel,
+            TreatmentType::Unknown("InterestTile".to_string()),
+        ] {
+            let json = serde_json::to_string(&treatment_type).unwrap();
+
+            assert_eq!(
+                serde_json::from_str::<TreatmentType>(&json).unwrap(),
+                treatment_type
+            );
+        }
+    }
+}
@@ -1,20 +1,50 @@
-use std::default;
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
 
 use reqwest::header;
 use serde::{Deserialize, Serialize};
 
-use super::{presence::PresenceType, RobloxApi, RobloxError};
+use super::{
+    games::{GameDetail, SortOrder},
+    presence::{PresenceType, UserPresence},
+    thumbnails::{ThumbnailSize, ThumbnailType, HEADSHOT_SIZE, THUMBNAILS_CHUNK_SIZE},
+    Limit, RobloxApi, RobloxError,
+};
 
 mod request_types;
 
 const FRIENDS_LIST_API: &str = "https://friends.roblox.com/v1/users/{user_id}/friends";
+const ONLINE_FRIENDS_API: &str = "https://friends.roblox.com/v1/users/{user_id}/friends/online";
+const FRIEND_RECOMMENDATIONS_API: &str =
+    "https://friends.roblox.com/v1/users/{user_id}/friends/recommendations";
+const DISMISS_FRIEND_RECOMMENDATION_API: &str =
+    "https://friends.roblox.com/v1/users/{target_id}/friends/recommendations/dismiss";
 const FRIENDS_STATUS_API: &str =
-    "https://friends.roblox.com/v1/users/{user_id}/friends/statuses?userIds[]={user_ids}";
+    "https://friends.roblox.com/v1/users/{user_id}/friends/statuses?userIds={user_ids}";
+
+/// The maximum amount of user ids sent in a single friend statuses request.
+const FRIENDS_STATUS_CHUNK_SIZE: usize = 50;
+
+/// How long results of [`RobloxApi::mutual_friends`] are cached for.
+const MUTUAL_FRIENDS_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum amount of universe ids sent in a single games details request.
+const GAMES_DETAILS_CHUNK_SIZE: usize = 50;
 
 const FRIENDS_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/friends/count";
 const FOLLOWERS_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followers/count";
 const FOLLOWINGS_COUNT_API: &str = "https://friends.roblox.com/v1/users/{user_id}/followings/count";
 
+const FOLLOWERS_API: &str =
+    "https://friends.roblox.com/v1/users/{user_id}/followers?limit={limit}&sortOrder={sort_order}";
+const FOLLOWINGS_API: &str =
+    "https://friends.roblox.com/v1/users/{user_id}/followings?limit={limit}&sortOrder={sort_order}";
+const FOLLOW_API: &str = "https://friends.roblox.com/v1/users/{target_id}/follow";
+const UNFOLLOW_API: &str = "https://friends.roblox.com/v1/users/{target_id}/unfollow";
+
 const FRIEND_REQUESTS_API: &str = "https://friends.roblox.com/v1/my/friends/requests";
 const PENDING_FRIEND_REQUESTS_API: &str =
     "https://friends.roblox.com/v1/user/friend-requests/count";
@@ -45,6 +75,7 @@ pub struct FriendUserInformation {
     pub created: String,
 
     pub presence_type: PresenceType,
+    pub is_online: bool,
 
     /// Whether the user is banned/terminated.
     #[serde(alias = "isBanned")]
@@ -52,6 +83,136 @@ pub struct FriendUserInformation {
 
     /// The user's verified badge status.
     pub has_verified_badge: bool,
+
+    /// How often the user plays with the logged in user. Higher is more frequent.
+    pub friend_frequent_score: i64,
+
+    /// Position of the user when friends are sorted by [`FriendUserInformation::friend_frequent_score`].
+    pub friend_frequent_rank: i64,
+
+    /// Private note stored locally by rsblox. Never returned by Roblox.
+    pub local_note: Option<FriendNote>,
+}
+
+/// Private nickname, note, tags and pinned flag of a user. Stored locally per account.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendNote {
+    pub nickname: Option<String>,
+    pub note: Option<String>,
+    pub tags: Vec<String>,
+
+    /// Pinned friends are sorted first.
+    pub pinned: bool,
+}
+
+impl FriendNote {
+    /// Whether the note holds no data and can be removed.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Model, representing an online friend with their full presence.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct OnlineFriend {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+
+    pub presence: UserPresence,
+}
+
+/// A server with friends in it.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendsActivityServer {
+    pub place_id: u64,
+    pub game_id: String,
+    pub user_ids: Vec<u64>,
+}
+
+/// An experience friends are playing right now.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendsActivity {
+    pub universe_id: u64,
+    pub game: Option<GameDetail>,
+    pub icon_url: Option<String>,
+
+    /// Every friend in the experience, including those whose server is hidden.
+    pub user_ids: Vec<u64>,
+
+    /// Servers with friends in them, busiest first.
+    pub servers: Vec<FriendsActivityServer>,
+}
+
+/// Model, representing a user Roblox suggests befriending ("People you may know").
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendRecommendation {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+
+    /// Usernames of mutual friends, if Roblox included them.
+    pub mutual_friends: Vec<String>,
+
+    /// Why the user was recommended, e.g. `MutualFriends`.
+    pub context_type: Option<String>,
+
+    /// Token for [`RobloxApi::token_thumbnail_url_bulk`].
+    pub thumbnail_token: Option<String>,
+}
+
+impl From<request_types::FriendRecommendationRaw> for FriendRecommendation {
+    fn from(value: request_types::FriendRecommendationRaw) -> Self {
+        Self {
+            user_id: value.id,
+            username: value.name,
+            display_name: value.display_name,
+            has_verified_badge: value.has_verified_badge,
+            mutual_friends: value.mutual_friends_list.unwrap_or_default(),
+            context_type: value.context_type,
+            thumbnail_token: value.thumbnail_token,
+        }
+    }
+}
+
+impl From<request_types::FriendUserInformationRaw> for FriendUserInformation {
+    fn from(value: request_types::FriendUserInformationRaw) -> Self {
+        Self {
+            user_id: value.id,
+            username: value.username,
+            display_name: value.display_name,
+
+            description: value.description,
+            created: value.created,
+
+            presence_type: PresenceType::from(value.presence_type.unwrap_or(0)),
+            is_online: value.is_online,
+            is_terminated: value.is_banned,
+
+            has_verified_badge: value.has_verified_badge,
+
+            friend_frequent_score: value.friend_frequent_score,
+            friend_frequent_rank: value.friend_frequent_rank,
+
+            local_note: None,
+        }
+    }
+}
+
+/// Model, representing a friend shared by the logged in user and another user.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct MutualFriend {
+    pub friend: FriendUserInformation,
+
+    /// `None` if the headshot isn't finished generating or failed to load.
+    pub headshot_url: Option<String>,
 }
 
 /// Model, representing a friend request.
@@ -81,7 +242,7 @@ pub struct FriendRequest {
     pub mutual_friends_list: Vec<String>,
 
     /// The origin source type associated with the friend request.
-    pub origin_source_type: String,
+    pub origin_source_type: FriendshipOriginSource,
 
     /// The source universe id which the request was sent in.
     /// # Note
@@ -92,27 +253,152 @@ pub struct FriendRequest {
     pub sent_at: String,
 }
 
-#[derive(
-    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
-)]
+/// Where a friend request was sent from.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum FriendshipOriginSource {
+    PlayerSearch,
+    QrCode,
+    InGame,
+    UserProfile,
+    QqContactImporter,
+    WeChatContactImporter,
+    ProfileShare,
+    PhoneContactImporter,
+    FriendRecommendations,
+
+    /// Roblox's own "Unknown" source, or a source unknown to rsblox, with the raw value
+    /// sent by Roblox. Sent to Roblox as 0.
+    Unknown(String),
+}
+
+impl Default for FriendshipOriginSource {
+    fn default() -> Self {
+        Self::Unknown("Unknown".to_string())
+    }
+}
+
+impl FriendshipOriginSource {
+    fn to_i32(&self) -> i32 {
+        match self {
+            Self::Unknown(_) => 0,
+            Self::PlayerSearch => 1,
+            Self::QrCode => 2,
+            Self::InGame => 3,
+            Self::UserProfile => 4,
+            Self::QqContactImporter => 5,
+            Self::WeChatContactImporter => 6,
+            Self::ProfileShare => 7,
+            Self::PhoneContactImporter => 8,
+            Self::FriendRecommendations => 9,
+        }
+    }
+}
+
+impl From<String> for FriendshipOriginSource {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "PlayerSearch" => Self::PlayerSearch,
+            "QrCode" => Self::QrCode,
+            "InGame" => Self::InGame,
+            "UserProfile" => Self::UserProfile,
+            "QqContactImporter" => Self::QqContactImporter,
+            "WeChatContactImporter" => Self::WeChatContactImporter,
+            "ProfileShare" => Self::ProfileShare,
+            "PhoneContactImporter" => Self::PhoneContactImporter,
+            "FriendRecommendations" => Self::FriendRecommendations,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<FriendshipOriginSource> for String {
+    fn from(value: FriendshipOriginSource) -> Self {
+        match value {
+            FriendshipOriginSource::PlayerSearch => "PlayerSearch".to_string(),
+            FriendshipOriginSource::QrCode => "QrCode".to_string(),
+            FriendshipOriginSource::InGame => "InGame".to_string(),
+            FriendshipOriginSource::UserProfile => "UserProfile".to_string(),
+            FriendshipOriginSource::QqContactImporter => "QqContactImporter".to_string(),
+            FriendshipOriginSource::WeChatContactImporter => "WeChatContactImporter".to_string(),
+            FriendshipOriginSource::ProfileShare => "ProfileShare".to_string(),
+            FriendshipOriginSource::PhoneContactImporter => "PhoneContactImporter".to_string(),
+            FriendshipOriginSource::FriendRecommendations => "FriendRecommendations".to_string(),
+            FriendshipOriginSource::Unknown(raw) => raw,
+        }
+    }
+}
+
+/// Filter used by bulk friend request actions. Empty filter matches every request.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendRequestFilter {
+    /// Only match requests from users that have at least one mutual friend.
+    pub mutual_friends_only: bool,
+
+    /// Only match requests sent from the specified universe.
+    pub source_universe_id: Option<u64>,
+
+    /// Only match requests from the specified users.
+    pub user_ids: Option<Vec<u64>>,
+}
+
+impl FriendRequestFilter {
+    /// Whether the friend request passes the filter.
+    pub fn matches(&self, friend_request: &FriendRequest) -> bool {
+        if self.mutual_friends_only && friend_request.mutual_friends_list.is_empty() {
+            return false;
+        }
+
+        if let Some(source_universe_id) = self.source_universe_id {
+            if friend_request.source_universe_id != source_universe_id {
+                return false;
+            }
+        }
+
+        if let Some(user_ids) = &self.user_ids {
+            if !user_ids.contains(&friend_request.user_id) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum FriendStatus {
     #[default]
     NotFriends,
     Friends,
     RequestSent,
     RequestReceived,
-}
 
-impl TryFrom<String> for FriendStatus {
-    type Error = RobloxError;
+    /// Friend status unknown to rsblox, with the raw value sent by Roblox.
+    Unknown(String),
+}
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+impl From<String> for FriendStatus {
+    fn from(value: String) -> Self {
         match value.as_str() {
-            "NotFriends" => Ok(Self::NotFriends),
-            "Friends" => Ok(Self::Friends),
-            "RequestSent" => Ok(Self::RequestSent),
-            "RequestReceived" => Ok(Self::RequestReceived),
-            _ => Err(RobloxError::MalformedResponse),
+            "NotFriends" => Self::NotFriends,
+            "Friends" => Self::Friends,
+            "RequestSent" => Self::RequestSent,
+            "RequestReceived" => Self::RequestReceived,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<FriendStatus> for String {
+    fn from(value: FriendStatus) -> Self {
+        match value {
+            FriendStatus::NotFriends => "NotFriends".to_string(),
+            FriendStatus::Friends => "Friends".to_string(),
+            FriendStatus::RequestSent => "RequestSent".to_string(),
+            FriendStatus::RequestReceived => "RequestReceived".to_string(),
+            FriendStatus::Unknown(raw) => raw,
         }
     }
 }
@@ -131,28 +417,296 @@ impl RobloxApi {
 
         let raw = Self::parse_to_raw::<request_types::FriendsListResponse>(response).await?;
 
-        let mut friends = Vec::new();
+        let friends = raw
+            .data
+            .into_iter()
+            .map(FriendUserInformation::from)
+            .collect();
+
+        Ok(friends)
+    }
+
+    /// Get list of online friends of the specified user with their full presence using
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends/online>.
+    pub async fn online_friends(&self, user_id: u64) -> Result<Vec<OnlineFriend>, RobloxError> {
+        let formatted_url = ONLINE_FRIENDS_API.replace("{user_id}", &user_id.to_string());
+
+        let cookie = self.cookie_string().await?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+
+        let raw = Self::parse_to_raw::<request_types::OnlineFriendsResponse>(response).await?;
+
+        let mut online_friends = Vec::new();
 
         for friend_raw in raw.data {
-            let friend = FriendUserInformation {
+            let presence_raw = friend_raw.user_presence;
+
+            online_friends.push(OnlineFriend {
                 user_id: friend_raw.id,
-                username: friend_raw.username,
+                username: friend_raw.name,
                 display_name: friend_raw.display_name,
+                presence: UserPresence {
+                    user_id: friend_raw.id,
+                    presence_type: PresenceType::from(presence_raw.user_presence_type),
+                    last_online: presence_raw.last_online.unwrap_or_default(),
+                    last_location: presence_raw.last_location.unwrap_or_default(),
+                    place_id: presence_raw.place_id,
+                    game_id: presence_raw.game_instance_id,
+                    universe_id: presence_raw.universe_id,
+                },
+            });
+        }
+
+        Ok(online_friends)
+    }
+
+    /// Get list of friends shared by the logged in user and the specified user, with their headshots.
+    /// Results are cached for a few minutes.
+    pub async fn mutual_friends(&self, user_id: u64) -> Result<Vec<MutualFriend>, RobloxError> {
+        if let Some((cached_at, mutual_friends)) =
+            self.mutual_friends_cache.read().await.get(&user_id)
+        {
+            if cached_at.elapsed() < MUTUAL_FRIENDS_CACHE_TTL {
+                return Ok(mutual_friends.clone());
+            }
+        }
+
+        let client_user_id = self.user_id().await?;
+
+        let (my_friends, users_friends) = tokio::try_join!(
+            self.friends_list(client_user_id),
+            self.friends_list(user_id),
+        )?;
+
+        let users_friend_ids = users_friends
+            .into_iter()
+            .map(|friend| friend.user_id)
+            .collect::<HashSet<_>>();
+
+        let friends = my_friends
+            .into_iter()
+            .filter(|friend| users_friend_ids.contains(&friend.user_id))
+            .collect::<Vec<_>>();
+
+        let mut headshot_urls = Vec::new();
+
+        for chunk in friends.chunks(THUMBNAILS_CHUNK_SIZE) {
+            let ids = chunk.iter().map(|friend| friend.user_id).collect();
+
+            // Headshots are cosmetic, so mutual friends are still returned if they fail to load.
+            let urls = self
+                .completed_thumbnail_url_bulk(ids, HEADSHOT_SIZE, ThumbnailType::AvatarHeadshot)
+                .await
+                .unwrap_or_else(|_| vec![None; chunk.len()]);
+
+            headshot_urls.extend(urls);
+        }
+
+        let mutual_friends = friends
+            .into_iter()
+            .zip(headshot_urls)
+            .map(|(friend, headshot_url)| MutualFriend {
+                friend,
+                headshot_url,
+            })
+            .collect::<Vec<_>>();
+
+        self.mutual_friends_cache
+            .write()
+            .await
+            .insert(user_id, (Instant::now(), mutual_friends.clone()));
+
+        Ok(mutual_friends)
+    }
 
-                description: friend_raw.description,
-                created: friend_raw.created,
+    /// Get list of followers of the specified user with cursor using <https://friends.roblox.com/v1/users/{user_id}/followers>.
+    pub async fn followers(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        sort_order: SortOrder,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FriendUserInformation>, Option<String>), RobloxError> {
+        self.follows_internal(FOLLOWERS_API, user_id, limit, sort_order, cursor)
+            .await
+    }
+
+    /// Get list of users the specified user follows with cursor using <https://friends.roblox.com/v1/users/{user_id}/followings>.
+    pub async fn followings(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        sort_order: SortOrder,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FriendUserInformation>, Option<String>), RobloxError> {
+        self.follows_internal(FOLLOWINGS_API, user_id, limit, sort_order, cursor)
+            .await
+    }
+
+    /// Get every follower of the specified user, going through all pages of [`RobloxApi::followers`].
+    pub async fn all_followers(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<FriendUserInformation>, RobloxError> {
+        self.all_follows_internal(FOLLOWERS_API, user_id).await
+    }
+
+    /// Get every user the specified user follows, going through all pages of [`RobloxApi::followings`].
+    pub async fn all_followings(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<FriendUserInformation>, RobloxError> {
+        self.all_follows_internal(FOLLOWINGS_API, user_id).await
+    }
+
+    /// Groups online friends of the specified user by the experience they're playing, most
+    /// popular first. Experiences are enriched with [`RobloxApi::games_details`] and icons.
+    pub async fn friends_activity(
+        &self,
+        user_id: u64,
+    ) -> Result<Vec<FriendsActivity>, RobloxError> {
+        let online_friends = self.online_friends(user_id).await?;
+
+        let mut activities = Vec::<FriendsActivity>::new();
+
+        for presence in online_friends
+            .into_iter()
+            .map(|friend| friend.presence)
+            .filter(|presence| presence.presence_type == PresenceType::InGame)
+        {
+            let Some(universe_id) = presence.universe_id else {
+                continue;
+            };
+
+            let activity = match activities
+                .iter_mut()
+                .position(|activity| activity.universe_id == universe_id)
+            {
+                Some(i) => &mut activities[i],
+                None => {
+                    activities.push(FriendsActivity {
+                        universe_id,
+                        ..Default::default()
+                    });
+                    activities.last_mut().unwrap()
+                }
+            };
 
-                presence_type: PresenceType::try_from(friend_raw.presence_type.unwrap_or(0))
-                    .unwrap_or(PresenceType::Offline),
-                is_terminated: friend_raw.is_banned,
+            activity.user_ids.push(presence.user_id);
 
-                has_verified_badge: friend_raw.has_verified_badge,
+            let (Some(place_id), Some(game_id)) = (presence.place_id, presence.game_id) else {
+                continue;
             };
 
-            friends.push(friend);
+            match activity
+                .servers
+                .iter_mut()
+                .find(|server| server.game_id == game_id)
+            {
+                Some(server) => server.user_ids.push(presence.user_id),
+                None => activity.servers.push(FriendsActivityServer {
+                    place_id,
+                    game_id,
+                    user_ids: vec![presence.user_id],
+                }),
+            }
         }
 
-        Ok(friends)
+        for activity in activities.iter_mut() {
+            activity
+                .servers
+                .sort_by_key(|server| Reverse(server.user_ids.len()));
+        }
+
+        activities.sort_by_key(|activity| Reverse(activity.user_ids.len()));
+
+        let universe_ids = activities
+            .iter()
+            .map(|activity| activity.universe_id)
+            .collect::<Vec<_>>();
+
+        // Details and icons are cosmetic, so activities are still returned if they fail to load.
+        let mut games = HashMap::new();
+
+        for chunk in universe_ids.chunks(GAMES_DETAILS_CHUNK_SIZE) {
+            if let Ok(details) = self.games_details(chunk.to_vec()).await {
+                games.extend(details.into_iter().map(|game| (game.universe_id, game)));
+            }
+        }
+
+        let mut icon_urls = HashMap::new();
+
+        for chunk in universe_ids.chunks(THUMBNAILS_CHUNK_SIZE) {
+            // Pending icons are `None`, so no placeholder is shown as if it was the real icon.
+            if let Ok(urls) = self
+                .completed_thumbnail_url_bulk(
+                    chunk.to_vec(),
+                    ThumbnailSize::S150x150,
+                    ThumbnailType::GameIcon,
+                )
+                .await
+            {
+                icon_urls.extend(chunk.iter().copied().zip(urls));
+            }
+        }
+
+        for activity in activities.iter_mut() {
+            activity.game = games.remove(&activity.universe_id);
+            activity.icon_url = icon_urls.remove(&activity.universe_id).flatten();
+        }
+
+        Ok(activities)
+    }
+
+    /// Get friend recommendations of the logged in user using
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends/recommendations>.
+    pub async fn friend_recommendations(&self) -> Result<Vec<FriendRecommendation>, RobloxError> {
+        let user_id = self.user_id().await?;
+        let formatted_url = FRIEND_RECOMMENDATIONS_API.replace("{user_id}", &user_id.to_string());
+
+        let cookie = self.cookie_string().await?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+
+        let raw =
+            Self::parse_to_raw::<request_types::FriendRecommendationsResponse>(response).await?;
+
+        Ok(raw
+            .data
+            .into_iter()
+            .map(FriendRecommendation::from)
+            .collect())
+    }
+
+    /// Hides a user from friend recommendations using
+    /// <https://friends.roblox.com/v1/users/{target_id}/friends/recommendations/dismiss>.
+    pub async fn dismiss_friend_recommendation(&self, target_id: u64) -> Result<(), RobloxError> {
+        match self.dismiss_friend_recommendation_internal(target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.dismiss_friend_recommendation_internal(target_id).await
+                }
+                _ => Err(e),
+            },
+        }
     }
 
     /// Gets friends count of specific user using <https://friends.roblox.com/v1/users/{user_id}/friends/count>
@@ -194,75 +748,100 @@ impl RobloxApi {
             .map(|res| res.count)
     }
 
-    /// Gets friend status with specific user using <https://friends.roblox.com/v1/users/{user_id}/friends/statuses?userIds[]={user_ids}>
+    /// Gets friend status with specific user using <https://friends.roblox.com/v1/users/{user_id}/friends/statuses>.
     pub async fn friend_status(&self, user_id: u64) -> Result<FriendStatus, RobloxError> {
-        let formatted_url = FRIENDS_STATUS_API
-            .replace(
-                "{user_id}",
-                &self.user_id().await.map(|user_id| user_id.to_string())?,
-            )
-            .replace("{user_ids}", &user_id.to_string());
+        self.friend_statuses(vec![user_id])
+            .await?
+            .remove(&user_id)
+            .ok_or(RobloxError::MalformedResponse)
+    }
 
+    /// Gets friend statuses with many users using <https://friends.roblox.com/v1/users/{user_id}/friends/statuses>.
+    /// User ids are sent in chunks, so any amount of them can be passed.
+    pub async fn friend_statuses(
+        &self,
+        user_ids: Vec<u64>,
+    ) -> Result<HashMap<u64, FriendStatus>, RobloxError> {
+        let client_user_id = self.user_id().await?;
         let cookie = self.cookie_string().await?;
 
-        let request_result = self
-            .reqwest_client
-            .get(formatted_url)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
+        let mut statuses = HashMap::new();
 
-        let response = Self::validate_request_result(request_result).await?;
+        for chunk in user_ids.chunks(FRIENDS_STATUS_CHUNK_SIZE) {
+            let formatted_user_ids = chunk
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join("&userIds=");
 
-        Self::parse_to_raw::<request_types::FriendsStatusResponse>(response)
-            .await
-            .map(|res| res.data[0].status)
+            let formatted_url = FRIENDS_STATUS_API
+                .replace("{user_id}", &client_user_id.to_string())
+                .replace("{user_ids}", &formatted_user_ids);
+
+            let request_result = self
+                .reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie.clone())
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::FriendsStatusResponse>(response).await?;
+
+            statuses.extend(raw.data.into_iter().map(|x| (x.id, x.status)));
+        }
+
+        Ok(statuses)
     }
 
     /// Get list of friend requests with cursor using <https://friends.roblox.com/v1/my/friends/requests>.
+    ///
+    /// If `exclude_blocked` is set, requests from users blocked by the logged in user are filtered out.
     pub async fn friend_requests(
         &self,
+        limit: Limit,
         cursor: Option<String>,
+        exclude_blocked: bool,
     ) -> Result<(Vec<FriendRequest>, Option<String>), RobloxError> {
-        let cookie = self.cookie_string().await?;
-        let mut formatted_url = format!("{}?limit={}", FRIEND_REQUESTS_API, 10);
+        let blocked_user_ids = match exclude_blocked {
+            true => Some(self.blocked_user_ids().await?),
+            false => None,
+        };
 
-        if let Some(cursor) = cursor {
-            formatted_url = format!("{}&cursor={}", formatted_url, cursor)
-        }
-
-        let request_result = self
-            .reqwest_client
-            .get(formatted_url)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
-
-        let response = Self::validate_request_result(request_result).await?;
+        self.friend_requests_internal(limit, cursor, blocked_user_ids.as_ref())
+            .await
+    }
 
-        let raw = Self::parse_to_raw::<request_types::FriendRequestsResponse>(response).await?;
+    /// Get all friend requests by paging through <https://friends.roblox.com/v1/my/friends/requests>.
+    ///
+    /// If `exclude_blocked` is set, requests from users blocked by the logged in user are filtered out.
+    pub async fn all_friend_requests(
+        &self,
+        exclude_blocked: bool,
+    ) -> Result<Vec<FriendRequest>, RobloxError> {
+        // Blocked users are fetched once for all pages.
+        let blocked_user_ids = match exclude_blocked {
+            true => Some(self.blocked_user_ids().await?),
+            false => None,
+        };
 
         let mut friend_requests = Vec::new();
+        let mut cursor = None;
 
-        for friend_request_raw in raw.data {
-            let friend_request = FriendRequest {
-                user_id: friend_request_raw.user_id,
-                username: friend_request_raw.username,
-                display_name: friend_request_raw.display_name,
-                description: friend_request_raw.description,
-                created: friend_request_raw.created,
-                is_terminated: friend_request_raw.is_terminated,
-                has_verified_badge: friend_request_raw.has_verified_badge,
-                mutual_friends_list: friend_request_raw.mutual_friends_list,
-                origin_source_type: friend_request_raw.friend_request.origin_source_type,
-                source_universe_id: friend_request_raw.friend_request.source_universe_id,
-                sent_at: friend_request_raw.friend_request.sent_at,
-            };
+        loop {
+            let (page, next_page_cursor) = self
+                .friend_requests_internal(Limit::Hundred, cursor, blocked_user_ids.as_ref())
+                .await?;
+
+            friend_requests.extend(page);
 
-            friend_requests.push(friend_request);
+            match next_page_cursor {
+                Some(next_page_cursor) => cursor = Some(next_page_cursor),
+                None => break,
+            }
         }
 
-        Ok((friend_requests, raw.next_page_cursor))
+        Ok(friend_requests)
     }
 
     /// Get count of pending friend requests using <https://friends.roblox.com/v1/user/friend-requests/count>.
@@ -315,15 +894,101 @@ impl RobloxApi {
         }
     }
 
+    /// Accepts every friend request matching the filter. Requests are accepted one by one,
+    /// and the result is reported for each requester id.
+    ///
+    /// Requests from blocked users are never accepted, their ids are returned separately
+    /// as the second item.
+    pub async fn accept_friend_requests(
+        &self,
+        filter: FriendRequestFilter,
+    ) -> Result<(Vec<(u64, Result<(), RobloxError>)>, Vec<u64>), RobloxError> {
+        let blocked_user_ids = self.blocked_user_ids().await?;
+        let friend_requests = self.all_friend_requests(false).await?;
+
+        let mut results = Vec::new();
+        let mut skipped_user_ids = Vec::new();
+
+        for friend_request in friend_requests.iter().filter(|x| filter.matches(x)) {
+            if blocked_user_ids.contains(&friend_request.user_id) {
+                skipped_user_ids.push(friend_request.user_id);
+                continue;
+            }
+
+            let result = self.accept_friend_request(friend_request.user_id).await;
+
+            results.push((friend_request.user_id, result));
+        }
+
+        Ok((results, skipped_user_ids))
+    }
+
+    /// Declines every friend request matching the filter. Requests are declined one by one,
+    /// and the result is reported for each requester id.
+    pub async fn decline_friend_requests(
+        &self,
+        filter: FriendRequestFilter,
+    ) -> Result<Vec<(u64, Result<(), RobloxError>)>, RobloxError> {
+        let friend_requests = self.all_friend_requests(false).await?;
+
+        let mut results = Vec::new();
+
+        for friend_request in friend_requests.iter().filter(|x| filter.matches(x)) {
+            let result = self.decline_friend_request(friend_request.user_id).await;
+
+            results.push((friend_request.user_id, result));
+        }
+
+        Ok(results)
+    }
+
     /// Sends friend request using <https://friends.roblox.com/v1/users/{target_id}/request-friendship>.
-    pub async fn send_friend_request(&self, target_id: u64) -> Result<(), RobloxError> {
-        match self.send_friend_request_internal(target_id).await {
+    pub async fn send_friend_request(
+        &self,
+        target_id: u64,
+        origin_source: FriendshipOriginSource,
+    ) -> Result<(), RobloxError> {
+        match self
+            .send_friend_request_internal(target_id, &origin_source)
+            .await
+        {
             Ok(_) => Ok(()),
             Err(e) => match e {
                 RobloxError::InvalidXcsrf(new_xcsrf) => {
                     self.set_xcsrf(new_xcsrf).await;
 
-                    self.send_friend_request_internal(target_id).await
+                    self.send_friend_request_internal(target_id, &origin_source)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Follows a user using <https://friends.roblox.com/v1/users/{target_id}/follow>.
+    pub async fn follow(&self, target_id: u64) -> Result<(), RobloxError> {
+        match self.follow_internal(FOLLOW_API, target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.follow_internal(FOLLOW_API, target_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unfollows a user using <https://friends.roblox.com/v1/users/{target_id}/unfollow>.
+    pub async fn unfollow(&self, target_id: u64) -> Result<(), RobloxError> {
+        match self.follow_internal(UNFOLLOW_API, target_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.follow_internal(UNFOLLOW_API, target_id).await
                 }
                 _ => Err(e),
             },
@@ -347,12 +1012,184 @@ impl RobloxApi {
 }
 
 mod internal {
+    use std::collections::HashSet;
+
     use reqwest::header;
     use serde_json::json;
 
-    use crate::client::{RobloxApi, RobloxError, XCSRF_HEADER};
+    use super::{request_types, FriendRequest, FriendUserInformation, FriendshipOriginSource};
+    use crate::client::{games::SortOrder, Limit, RobloxApi, RobloxError, XCSRF_HEADER};
 
     impl RobloxApi {
+        /// Shared implementation of [`RobloxApi::followers`] and [`RobloxApi::followings`],
+        /// as both endpoints have the same parameters and response.
+        pub(super) async fn follows_internal(
+            &self,
+            api: &str,
+            user_id: u64,
+            limit: Limit,
+            sort_order: SortOrder,
+            cursor: Option<String>,
+        ) -> Result<(Vec<FriendUserInformation>, Option<String>), RobloxError> {
+            let sort_order = match sort_order {
+                SortOrder::Ascending => "Asc",
+                SortOrder::Descending => "Desc",
+            };
+
+            let mut formatted_url = api
+                .replace("{user_id}", &user_id.to_string())
+                .replace("{limit}", &limit.to_u64().to_string())
+                .replace("{sort_order}", sort_order);
+
+            if let Some(cursor) = cursor {
+                formatted_url = format!("{}&cursor={}", formatted_url, cursor);
+            }
+
+            let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::FollowsResponse>(response).await?;
+
+            let users = raw
+                .data
+                .into_iter()
+                .map(FriendUserInformation::from)
+                .collect();
+
+            Ok((users, raw.next_page_cursor))
+        }
+
+        /// Shared implementation of [`RobloxApi::all_followers`] and [`RobloxApi::all_followings`].
+        pub(super) async fn all_follows_internal(
+            &self,
+            api: &str,
+            user_id: u64,
+        ) -> Result<Vec<FriendUserInformation>, RobloxError> {
+            let mut users = Vec::new();
+            let mut cursor = None;
+
+            loop {
+                let (page, next_page_cursor) = self
+                    .follows_internal(api, user_id, Limit::Hundred, SortOrder::Ascending, cursor)
+                    .await?;
+
+                users.extend(page);
+
+                match next_page_cursor {
+                    Some(next_page_cursor) => cursor = Some(next_page_cursor),
+                    None => break,
+                }
+            }
+
+            Ok(users)
+        }
+
+        /// Shared implementation of [`RobloxApi::follow`] and [`RobloxApi::unfollow`].
+        pub(super) async fn follow_internal(
+            &self,
+            api: &str,
+            target_id: u64,
+        ) -> Result<(), RobloxError> {
+            let formatted_url = api.replace("{target_id}", &target_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn dismiss_friend_recommendation_internal(
+            &self,
+            target_id: u64,
+        ) -> Result<(), RobloxError> {
+            let formatted_url = super::DISMISS_FRIEND_RECOMMENDATION_API
+                .replace("{target_id}", &target_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+            let xcsrf = self.xcsrf().await;
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, xcsrf)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        /// Single page of [`RobloxApi::friend_requests`], without requests from `blocked_user_ids`.
+        pub(super) async fn friend_requests_internal(
+            &self,
+            limit: Limit,
+            cursor: Option<String>,
+            blocked_user_ids: Option<&HashSet<u64>>,
+        ) -> Result<(Vec<FriendRequest>, Option<String>), RobloxError> {
+            let cookie = self.cookie_string().await?;
+            let mut formatted_url =
+                format!("{}?limit={}", super::FRIEND_REQUESTS_API, limit.to_u64());
+
+            if let Some(cursor) = cursor {
+                formatted_url = format!("{}&cursor={}", formatted_url, cursor)
+            }
+
+            let request_result = self
+                .reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+
+            let raw = Self::parse_to_raw::<request_types::FriendRequestsResponse>(response).await?;
+
+            let mut friend_requests = Vec::new();
+
+            for friend_request_raw in raw.data {
+                let friend_request = FriendRequest {
+                    user_id: friend_request_raw.user_id,
+                    username: friend_request_raw.username,
+                    display_name: friend_request_raw.display_name,
+                    description: friend_request_raw.description,
+                    created: friend_request_raw.created,
+                    is_terminated: friend_request_raw.is_terminated,
+                    has_verified_badge: friend_request_raw.has_verified_badge,
+                    mutual_friends_list: friend_request_raw.mutual_friends_list,
+                    origin_source_type: FriendshipOriginSource::from(
+                        friend_request_raw.friend_request.origin_source_type,
+                    ),
+                    source_universe_id: friend_request_raw.friend_request.source_universe_id,
+                    sent_at: friend_request_raw.friend_request.sent_at,
+                };
+
+                friend_requests.push(friend_request);
+            }
+
+            if let Some(blocked_user_ids) = blocked_user_ids {
+                friend_requests.retain(|request| !blocked_user_ids.contains(&request.user_id));
+            }
+
+            Ok((friend_requests, raw.next_page_cursor))
+        }
+
         pub(super) async fn accept_friend_request_internal(
             &self,
             requester_id: u64,
@@ -406,6 +1243,7 @@ mod internal {
         pub(super) async fn send_friend_request_internal(
             &self,
             target_id: u64,
+            origin_source: &FriendshipOriginSource,
         ) -> Result<(), RobloxError> {
             let formatted_url =
                 super::SEND_FRIEND_REQUEST_API.replace("{target_id}", &target_id.to_string());
@@ -413,9 +1251,8 @@ mod internal {
             let cookie = self.cookie_string().await?;
             let xcsrf = self.xcsrf().await;
 
-            // TODO: maybe add settable friendshipOriginSourceType parameter
             let body = json!({
-                "friendshipOriginSourceType": 0
+                "friendshipOriginSourceType": origin_source.to_i32()
             });
 
             let request_result = self
@@ -456,3 +1293,68 @@ mod internal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{request_types::FriendsStatusResponse, FriendStatus, FriendshipOriginSource};
+
+    #[test]
+    fn friend_status_from_known_value() {
+        assert_eq!(
+            FriendStatus::from("RequestSent".to_string()),
+            FriendStatus::RequestSent
+        );
+    }
+
+    #[test]
+    fn friend_status_from_unknown_value() {
+        assert_eq!(
+            FriendStatus::from("Blocked".to_string()),
+            FriendStatus::Unknown("Blocked".to_string())
+        );
+    }
+
+    #[test]
+    fn friend_statuses_response_with_unknown_status() {
+        let response = serde_json::from_str::<FriendsStatusResponse>(
+            r#"{"data": [{"id": 1, "status": "Friends"}, {"id": 2, "status": "Blocked"}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.data[0].status, FriendStatus::Friends);
+        assert_eq!(
+            response.data[1].status,
+            FriendStatus::Unknown("Blocked".to_string())
+        );
+    }
+
+    #[test]
+    fn friend_status_round_trips() {
+        let status = FriendStatus::Unknown("Blocked".to_string());
+        let json = serde_json::to_string(&status).unwrap();
+
+        assert_eq!(json, r#""Blocked""#);
+        assert_eq!(serde_json::from_str::<FriendStatus>(&json).unwrap(), status);
+    }
+
+    #[test]
+    fn friendship_origin_source_from_unknown_value() {
+        assert_eq!(
+            FriendshipOriginSource::from("DiscordInvite".to_string()),
+            FriendshipOriginSource::Unknown("DiscordInvite".to_string())
+        );
+        assert_eq!(FriendshipOriginSource::default().to_i32(), 0);
+    }
+
+    #[test]
+    fn friendship_origin_source_round_trips() {
+        let source = FriendshipOriginSource::Unknown("DiscordInvite".to_string());
+        let json = serde_json::to_string(&source).unwrap();
+
+        assert_eq!(json, r#""DiscordInvite""#);
+        assert_eq!(
+            serde_json::from_str::<FriendshipOriginSource>(&json).unwrap(),
+            source
+        );
+    }
+}
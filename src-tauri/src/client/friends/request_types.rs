@@ -103,6 +103,46 @@ pub(super) struct FriendsListResponse {
     pub data: Vec<FriendUserInformationRaw>,
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FollowsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+
+    pub data: Vec<FriendUserInformationRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OnlineFriendsResponse {
+    pub data: Vec<OnlineFriendRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OnlineFriendRaw {
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+    pub user_presence: OnlineFriendPresenceRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OnlineFriendPresenceRaw {
+    #[serde(rename = "UserPresenceType")]
+    pub user_presence_type: String,
+    #[serde(rename = "UserLocationType")]
+    pub user_location_type: String,
+    pub last_location: Option<String>,
+    pub last_online: Option<String>,
+
+    pub place_id: Option<u64>,
+    pub root_place_id: Option<u64>,
+    pub game_instance_id: Option<String>,
+    pub universe_id: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct CountBasedResponse {
@@ -123,3 +163,23 @@ pub(super) struct FriendRequestsResponse {
 pub(super) struct PendingFriendRequestsResponse {
     pub count: u64,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRecommendationsResponse {
+    pub data: Vec<FriendRecommendationRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRecommendationRaw {
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub has_verified_badge: bool,
+
+    pub mutual_friends_list: Option<Vec<String>>,
+    pub context_type: Option<String>,
+    pub thumbnail_token: Option<String>,
+}
@@ -5,16 +5,23 @@ use reqwest::header;
 use reqwest::header::HeaderValue;
 use serde::{Deserialize, Serialize};
 
-use super::{RobloxApi, RobloxError};
+use super::{Limit, RobloxApi, RobloxError};
 
+mod players;
+mod private_servers;
+mod ranking;
 mod request_types;
 
+pub use players::*;
+pub use private_servers::*;
+pub use ranking::*;
+
 const GAMES_DETAILS_API: &str = "https://games.roblox.com/v1/games?universeIds={universe_ids}";
 const PLACE_DETAILS_API: &str =
     "https://games.roblox.com/v1/games/multiget-place-details?placeIds={place_ids}";
 
 const GAME_MEDIA_API: &str = "https://games.roblox.com/v2/games/{universe_id}/media";
-const GAME_SERVERS_API: &str = "https://games.roblox.com/v1/games/{place_id}/servers/{servers_type}?sortOrder={sort_order}&excludeFullGames={exclude_full_games}&limit=10";
+const GAME_SERVERS_API: &str = "https://games.roblox.com/v1/games/{place_id}/servers/{servers_type}?sortOrder={sort_order}&excludeFullGames={exclude_full_games}&limit={limit}";
 
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
@@ -41,20 +48,32 @@ impl TryFrom<String> for AvatarType {
 
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum GameMediaType {
     #[default]
     Image,
     YouTubeVideo,
-}
 
-impl TryFrom<String> for GameMediaType {
-    type Error = RobloxError;
+    /// Media type unknown to rsblox, with the raw value sent by Roblox.
+    Unknown(String),
+}
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+impl From<String> for GameMediaType {
+    fn from(value: String) -> Self {
         match value.as_str() {
-            "Image" => Ok(Self::Image),
-            "YouTubeVideo" => Ok(Self::YouTubeVideo),
-            _ => Err(RobloxError::MalformedResponse),
+            "Image" => Self::Image,
+            "YouTubeVideo" => Self::YouTubeVideo,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<GameMediaType> for String {
+    fn from(value: GameMediaType) -> Self {
+        match value {
+            GameMediaType::Image => "Image".to_string(),
+            GameMediaType::YouTubeVideo => "YouTubeVideo".to_string(),
+            GameMediaType::Unknown(raw) => raw,
         }
     }
 }
@@ -93,6 +112,9 @@ pub struct GameDetail {
     pub created: String,
     pub updated: String,
 
+    /// Whether private servers can be bought, see [`RobloxApi::private_server_options`].
+    pub create_vip_servers_allowed: bool,
+
     /// Avatar type. Possible values are MorphToR6, MorphToR15, and PlayerChoice
     pub universe_avatar_type: AvatarType,
 
@@ -162,9 +184,19 @@ pub struct GameMedia {
 pub enum ServerType {
     #[default]
     Public,
+    /// Servers with friends of the logged in user in them.
     Friends,
 }
 
+impl ServerType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "0",
+            Self::Friends => "1",
+        }
+    }
+}
+
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub enum SortOrder {
@@ -183,11 +215,22 @@ pub struct GameServer {
 
     pub player_tokens: Vec<String>,
 
-    // pub players: Vec<String>,
+    /// Players of the server. Roblox only fills this in for [`ServerType::Friends`].
+    pub players: Vec<GameServerPlayer>,
+
     pub fps: f32,
     pub ping: u64,
 }
 
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GameServerPlayer {
+    pub player_token: String,
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+}
+
 impl RobloxApi {
     /// Gets games' details using <https://groups.roblox.com/v1/groups/{group_id}/roles>.
     pub async fn games_details(
@@ -244,6 +287,7 @@ impl RobloxApi {
                 max_players: game_detail.max_players,
                 created: game_detail.created,
                 updated: game_detail.updated,
+                create_vip_servers_allowed: game_detail.create_vip_servers_allowed,
 
                 universe_avatar_type: AvatarType::try_from(game_detail.universe_avatar_type)?,
                 genre: game_detail.genre,
@@ -342,7 +386,7 @@ impl RobloxApi {
         for game_media in raw.data {
             game_media_result.push(GameMedia {
                 asset_type_id: game_media.asset_type_id,
-                asset_type: GameMediaType::try_from(game_media.asset_type)?,
+                asset_type: GameMediaType::from(game_media.asset_type),
                 approved: game_media.approved,
 
                 image_id: game_media.image_id,
@@ -362,13 +406,10 @@ impl RobloxApi {
         servers_type: Option<ServerType>,
         sort_order: Option<SortOrder>,
         exclude_full_games: Option<bool>,
+        limit: Option<Limit>,
         cursor: Option<String>,
     ) -> Result<(Vec<GameServer>, Option<String>), RobloxError> {
-        let servers_type = if servers_type.unwrap_or_default() == ServerType::Public {
-            "0"
-        } else {
-            "1"
-        };
+        let servers_type = servers_type.unwrap_or_default();
 
         let sort_order = if sort_order.unwrap_or_default() == SortOrder::Ascending {
             1
@@ -389,9 +430,10 @@ impl RobloxApi {
 
         let mut formatted_url = GAME_SERVERS_API
             .replace("{place_id}", &place_id.to_string())
-            .replace("{servers_type}", servers_type)
+            .replace("{servers_type}", servers_type.as_str())
             .replace("{sort_order}", &sort_order.to_string())
-            .replace("{exclude_full_games}", exclude_full_games);
+            .replace("{exclude_full_games}", exclude_full_games)
+            .replace("{limit}", &limit.unwrap_or_default().to_u64().to_string());
 
         if let Some(cursor) = cursor {
             formatted_url = format!("{}&cursor={}", formatted_url, cursor);
@@ -415,6 +457,16 @@ impl RobloxApi {
                 max_players: game_server.max_players,
                 playing: game_server.playing,
                 player_tokens: game_server.player_tokens,
+                players: game_server
+                    .players
+                    .into_iter()
+                    .map(|player| GameServerPlayer {
+                        player_token: player.player_token,
+                        user_id: player.id,
+                        username: player.name,
+                        display_name: player.display_name,
+                    })
+                    .collect(),
                 fps: game_server.fps,
                 ping: game_server.ping,
             })
@@ -422,4 +474,82 @@ impl RobloxApi {
 
         Ok((game_servers, raw.next_page_cursor))
     }
+
+    /// Gets servers of a game going through every page of [`RobloxApi::game_servers`],
+    /// stopping after `max_pages` pages if given. Popular games have thousands of servers,
+    /// so a cap is recommended.
+    pub async fn all_game_servers(
+        &self,
+        place_id: u64,
+        servers_type: Option<ServerType>,
+        sort_order: Option<SortOrder>,
+        exclude_full_games: Option<bool>,
+        max_pages: Option<usize>,
+    ) -> Result<Vec<GameServer>, RobloxError> {
+        let mut game_servers = Vec::new();
+        let mut cursor = None;
+        let mut pages = 0;
+
+        loop {
+            let (page, next_page_cursor) = self
+                .game_servers(
+                    place_id,
+                    servers_type.clone(),
+                    sort_order.clone(),
+                    exclude_full_games,
+                    Some(Limit::Hundred),
+                    cursor,
+                )
+                .await?;
+
+            game_servers.extend(page);
+            pages += 1;
+
+            if max_pages.is_some_and(|max_pages| pages >= max_pages) {
+                break;
+            }
+
+            match next_page_cursor {
+                Some(next_page_cursor) => cursor = Some(next_page_cursor),
+                None => break,
+            }
+        }
+
+        Ok(game_servers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GameMediaType;
+
+    #[test]
+    fn game_media_type_from_known_value() {
+        assert_eq!(
+            GameMediaType::from("YouTubeVideo".to_string()),
+            GameMediaType::YouTubeVideo
+        );
+    }
+
+    #[test]
+    fn game_media_type_from_unknown_value() {
+        assert_eq!(
+            GameMediaType::from("Video".to_string()),
+            GameMediaType::Unknown("Video".to_string())
+        );
+    }
+
+    #[test]
+    fn game_media_type_deserializes_unknown_value() {
+        let media_types =
+            serde_json::from_str::<Vec<GameMediaType>>(r#"["Image", "Video"]"#).unwrap();
+
+        assert_eq!(
+            media_types,
+            vec![
+                GameMediaType::Image,
+                GameMediaType::Unknown("Video".to_string())
+            ]
+        );
+    }
 }
@@ -0,0 +1,106 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{
+    thumbnails::{ThumbnailType, HEADSHOT_SIZE, THUMBNAILS_CHUNK_SIZE},
+    RobloxApi, RobloxError,
+};
+
+/// How long headshots of the logged in user's friends are cached for.
+const FRIEND_HEADSHOTS_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// A player of a game server, resolved from its player token.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ServerPlayer {
+    pub player_token: String,
+    pub headshot_url: Option<String>,
+
+    /// Id of the friend with the same headshot, if any.
+    pub friend_id: Option<u64>,
+}
+
+impl RobloxApi {
+    /// Resolves player tokens of game servers to headshots and marks the players whose
+    /// headshot matches one of the logged in user's friends.
+    ///
+    /// Friends are matched by their headshot image, so a player with the exact same avatar
+    /// as a friend (e.g. an untouched default avatar) is reported as that friend.
+    pub async fn server_players(
+        &self,
+        player_tokens: Vec<String>,
+    ) -> Result<Vec<ServerPlayer>, RobloxError> {
+        let mut headshot_urls = Vec::new();
+
+        for chunk in player_tokens.chunks(THUMBNAILS_CHUNK_SIZE) {
+            // Only finished headshots are kept, as pending and blocked ones share placeholder
+            // urls, which would match every friend with a pending or blocked headshot.
+            headshot_urls.extend(
+                self.completed_token_thumbnail_url_bulk(
+                    chunk.to_vec(),
+                    HEADSHOT_SIZE,
+                    ThumbnailType::AvatarHeadshot,
+                )
+                .await?,
+            );
+        }
+
+        // Not being able to load friends (e.g. when logged out) only means no one is marked.
+        let friend_headshots = self.friend_headshots().await.unwrap_or_default();
+
+        Ok(player_tokens
+            .into_iter()
+            .zip(headshot_urls)
+            .map(|(player_token, headshot_url)| ServerPlayer {
+                friend_id: headshot_url
+                    .as_ref()
+                    .and_then(|url| friend_headshots.get(url).copied()),
+                player_token,
+                headshot_url,
+            })
+            .collect())
+    }
+
+    /// Finished headshot urls of the logged in user's friends, mapped to their user ids.
+    async fn friend_headshots(&self) -> Result<HashMap<String, u64>, RobloxError> {
+        if let Some((cached_at, friend_headshots)) = &*self.friend_headshots_cache.read().await {
+            if cached_at.elapsed() < FRIEND_HEADSHOTS_CACHE_TTL {
+                return Ok(friend_headshots.clone());
+            }
+        }
+
+        let friend_ids = self
+            .friends_list(self.user_id().await?)
+            .await?
+            .into_iter()
+            .map(|friend| friend.user_id)
+            .collect::<Vec<_>>();
+
+        let mut friend_headshots = HashMap::new();
+
+        for chunk in friend_ids.chunks(THUMBNAILS_CHUNK_SIZE) {
+            let urls = self
+                .completed_thumbnail_url_bulk(
+                    chunk.to_vec(),
+                    HEADSHOT_SIZE,
+                    ThumbnailType::AvatarHeadshot,
+                )
+                .await?;
+
+            friend_headshots.extend(
+                urls.into_iter()
+                    .zip(chunk.iter().copied())
+                    .filter_map(|(url, friend_id)| Some((url?, friend_id))),
+            );
+        }
+
+        *self.friend_headshots_cache.write().await =
+            Some((Instant::now(), friend_headshots.clone()));
+
+        Ok(friend_headshots)
+    }
+}
@@ -0,0 +1,484 @@
+use reqwest::header;
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+
+use super::request_types::{
+    PrivateServerDetailsRaw, PrivateServerOptionsRaw, PrivateServerPermissionsRaw,
+    PrivateServersResponse,
+};
+use crate::client::{Limit, RobloxApi, RobloxError};
+
+const PRIVATE_SERVERS_API: &str =
+    "https://games.roblox.com/v1/games/{place_id}/private-servers?limit={limit}";
+
+/// Used both for reading the private server options of a game and creating a server.
+const PRIVATE_SERVER_OPTIONS_API: &str =
+    "https://games.roblox.com/v1/games/vip-servers/{universe_id}";
+
+const PRIVATE_SERVER_API: &str = "https://games.roblox.com/v1/vip-servers/{vip_server_id}";
+const PRIVATE_SERVER_PERMISSIONS_API: &str =
+    "https://games.roblox.com/v1/vip-servers/{vip_server_id}/permissions";
+
+/// A private server of a place, which the logged in user owns or has access to.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct PrivateServer {
+    pub vip_server_id: u64,
+    pub name: String,
+
+    /// Used to join the server with the `open_private_server` command.
+    pub access_code: String,
+    pub owner: PrivateServerUser,
+
+    /// Job id of the running server, `None` if no one is playing.
+    pub game_id: Option<String>,
+    pub max_players: usize,
+    pub playing: usize,
+    pub player_tokens: Vec<String>,
+
+    pub fps: f32,
+    pub ping: u64,
+}
+
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PrivateServerUser {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+}
+
+/// Whether private servers can be bought for a game, and at which price.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PrivateServerOptions {
+    pub universe_id: u64,
+    pub creation_allowed: bool,
+
+    /// Price in robux per month. `None` if creating is not allowed, `Some(0)` if free.
+    pub price: Option<u64>,
+}
+
+/// Configuration of a private server, only available to its owner.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PrivateServerDetails {
+    pub vip_server_id: u64,
+    pub name: String,
+    pub universe_id: u64,
+    pub root_place_id: u64,
+
+    /// Inactive servers can't be joined, but keep their subscription.
+    pub active: bool,
+
+    /// Code of the share link, `None` if the link was never generated.
+    pub link_code: Option<String>,
+    pub link: Option<String>,
+
+    pub subscription: PrivateServerSubscription,
+    pub permissions: PrivateServerPermissions,
+}
+
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PrivateServerSubscription {
+    pub active: bool,
+    pub expired: bool,
+    pub expiration_date: Option<String>,
+    pub price: Option<u64>,
+    pub can_renew: bool,
+}
+
+/// Who can join a private server besides its owner.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PrivateServerPermissions {
+    pub friends_allowed: bool,
+
+    /// Whitelisted users.
+    pub users: Vec<PrivateServerUser>,
+}
+
+/// Changes to a private server, see [`RobloxApi::update_private_server`].
+/// Fields left as `None` are not changed.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrivateServerUpdate {
+    pub name: Option<String>,
+    pub active: Option<bool>,
+
+    /// Generates a new share link, invalidating the old one.
+    pub new_link_code: bool,
+}
+
+/// Changes to a private server's whitelist, see [`RobloxApi::set_private_server_permissions`].
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrivateServerPermissionsUpdate {
+    pub friends_allowed: Option<bool>,
+    pub users_to_add: Vec<u64>,
+    pub users_to_remove: Vec<u64>,
+}
+
+impl RobloxApi {
+    /// Gets private servers of a place the logged in user owns or has access to using
+    /// <https://games.roblox.com/v1/games/{place_id}/private-servers>.
+    ///
+    /// Servers are joined with their `access_code`.
+    pub async fn private_servers(
+        &self,
+        place_id: u64,
+        limit: Option<Limit>,
+        cursor: Option<String>,
+    ) -> Result<(Vec<PrivateServer>, Option<String>), RobloxError> {
+        let cookie_string = self.cookie_string().await?;
+
+        let mut formatted_url = PRIVATE_SERVERS_API
+            .replace("{place_id}", &place_id.to_string())
+            .replace("{limit}", &limit.unwrap_or_default().to_u64().to_string());
+
+        if let Some(cursor) = cursor {
+            formatted_url = format!("{}&cursor={}", formatted_url, cursor);
+        }
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<PrivateServersResponse>(response).await?;
+
+        let private_servers = raw
+            .data
+            .into_iter()
+            .map(|private_server| PrivateServer {
+                vip_server_id: private_server.vip_server_id,
+                name: private_server.name,
+                access_code: private_server.access_code,
+                owner: PrivateServerUser {
+                    user_id: private_server.owner.id,
+                    username: private_server.owner.name,
+                    display_name: private_server.owner.display_name,
+                },
+                game_id: private_server.id,
+                max_players: private_server.max_players,
+                playing: private_server.playing,
+                player_tokens: private_server.player_tokens,
+                fps: private_server.fps,
+                ping: private_server.ping,
+            })
+            .collect();
+
+        Ok((private_servers, raw.next_page_cursor))
+    }
+
+    /// Gets whether private servers can be created for a game and their price using
+    /// <https://games.roblox.com/v1/games/vip-servers/{universe_id}>.
+    pub async fn private_server_options(
+        &self,
+        universe_id: u64,
+    ) -> Result<PrivateServerOptions, RobloxError> {
+        let game_detail = self.game_details(universe_id).await?;
+
+        if !game_detail.create_vip_servers_allowed {
+            return Ok(PrivateServerOptions {
+                universe_id,
+                creation_allowed: false,
+                price: None,
+            });
+        }
+
+        let formatted_url =
+            PRIVATE_SERVER_OPTIONS_API.replace("{universe_id}", &universe_id.to_string());
+
+        let cookie_string = self
+            .cookie_string()
+            .await
+            .unwrap_or(HeaderValue::from_static(""));
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<PrivateServerOptionsRaw>(response).await?;
+
+        Ok(PrivateServerOptions {
+            universe_id,
+            creation_allowed: raw.is_enabled,
+            price: raw.price,
+        })
+    }
+
+    /// Buys a private server using <https://games.roblox.com/v1/games/vip-servers/{universe_id}>.
+    ///
+    /// `expected_price` has to match the current price (see [`RobloxApi::private_server_options`]),
+    /// so the user is never charged more than what they were shown. `purchase_confirmed` has to
+    /// come from the user confirming the purchase, Roblox rejects unconfirmed purchases.
+    pub async fn create_private_server(
+        &self,
+        universe_id: u64,
+        name: String,
+        expected_price: u64,
+        purchase_confirmed: bool,
+    ) -> Result<PrivateServerDetails, RobloxError> {
+        match self
+            .create_private_server_internal(universe_id, &name, expected_price, purchase_confirmed)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.create_private_server_internal(
+                        universe_id,
+                        &name,
+                        expected_price,
+                        purchase_confirmed,
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Gets the configuration of an owned private server using
+    /// <https://games.roblox.com/v1/vip-servers/{vip_server_id}>.
+    pub async fn private_server(
+        &self,
+        vip_server_id: u64,
+    ) -> Result<PrivateServerDetails, RobloxError> {
+        let formatted_url =
+            PRIVATE_SERVER_API.replace("{vip_server_id}", &vip_server_id.to_string());
+
+        let cookie_string = self.cookie_string().await?;
+
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<PrivateServerDetailsRaw>(response).await?;
+
+        Ok(raw.into())
+    }
+
+    /// Renames, (de)activates or regenerates the share link of an owned private server using
+    /// <https://games.roblox.com/v1/vip-servers/{vip_server_id}>.
+    pub async fn update_private_server(
+        &self,
+        vip_server_id: u64,
+        update: PrivateServerUpdate,
+    ) -> Result<PrivateServerDetails, RobloxError> {
+        match self
+            .update_private_server_internal(vip_server_id, &update)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.update_private_server_internal(vip_server_id, &update)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Changes the whitelist of an owned private server using
+    /// <https://games.roblox.com/v1/vip-servers/{vip_server_id}/permissions>.
+    pub async fn set_private_server_permissions(
+        &self,
+        vip_server_id: u64,
+        update: PrivateServerPermissionsUpdate,
+    ) -> Result<PrivateServerPermissions, RobloxError> {
+        match self
+            .set_private_server_permissions_internal(vip_server_id, &update)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_private_server_permissions_internal(vip_server_id, &update)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+impl From<PrivateServerDetailsRaw> for PrivateServerDetails {
+    fn from(raw: PrivateServerDetailsRaw) -> Self {
+        Self {
+            vip_server_id: raw.id,
+            name: raw.name,
+            universe_id: raw.game.id,
+            root_place_id: raw.game.root_place.id,
+            active: raw.active,
+            link_code: raw.join_code,
+            link: raw.link,
+            subscription: PrivateServerSubscription {
+                active: raw.subscription.active,
+                expired: raw.subscription.expired,
+                expiration_date: raw.subscription.expiration_date,
+                price: raw.subscription.price,
+                can_renew: raw.subscription.can_renew,
+            },
+            permissions: raw.permissions.into(),
+        }
+    }
+}
+
+impl From<PrivateServerPermissionsRaw> for PrivateServerPermissions {
+    fn from(raw: PrivateServerPermissionsRaw) -> Self {
+        Self {
+            friends_allowed: raw.friends_allowed,
+            users: raw
+                .users
+                .into_iter()
+                .map(|user| PrivateServerUser {
+                    user_id: user.id,
+                    username: user.name,
+                    display_name: user.display_name,
+                })
+                .collect(),
+        }
+    }
+}
+
+mod internal {
+    use reqwest::header;
+
+    use super::{
+        PrivateServerDetails, PrivateServerPermissions, PrivateServerPermissionsUpdate,
+        PrivateServerUpdate,
+    };
+    use crate::client::{
+        games::request_types::{PrivateServerDetailsRaw, PrivateServerPermissionsRaw},
+        RobloxApi, RobloxError, XCSRF_HEADER,
+    };
+
+    impl RobloxApi {
+        pub(super) async fn create_private_server_internal(
+            &self,
+            universe_id: u64,
+            name: &str,
+            expected_price: u64,
+            purchase_confirmed: bool,
+        ) -> Result<PrivateServerDetails, RobloxError> {
+            let formatted_url = super::PRIVATE_SERVER_OPTIONS_API
+                .replace("{universe_id}", &universe_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+
+            let json = serde_json::json!({
+                "name": name,
+                "expectedPrice": expected_price,
+                "isPurchaseConfirmed": purchase_confirmed,
+            });
+
+            let request_result = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<PrivateServerDetailsRaw>(response).await?;
+
+            Ok(raw.into())
+        }
+
+        pub(super) async fn update_private_server_internal(
+            &self,
+            vip_server_id: u64,
+            update: &PrivateServerUpdate,
+        ) -> Result<PrivateServerDetails, RobloxError> {
+            let formatted_url =
+                super::PRIVATE_SERVER_API.replace("{vip_server_id}", &vip_server_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+
+            let mut json = serde_json::json!({
+                "newJoinCode": update.new_link_code,
+            });
+
+            if let Some(name) = &update.name {
+                json["name"] = name.clone().into();
+            }
+
+            if let Some(active) = update.active {
+                json["active"] = active.into();
+            }
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<PrivateServerDetailsRaw>(response).await?;
+
+            Ok(raw.into())
+        }
+
+        pub(super) async fn set_private_server_permissions_internal(
+            &self,
+            vip_server_id: u64,
+            update: &PrivateServerPermissionsUpdate,
+        ) -> Result<PrivateServerPermissions, RobloxError> {
+            let formatted_url = super::PRIVATE_SERVER_PERMISSIONS_API
+                .replace("{vip_server_id}", &vip_server_id.to_string());
+
+            let cookie = self.cookie_string().await?;
+
+            let mut json = serde_json::json!({
+                "usersToAdd": update.users_to_add,
+                "usersToRemove": update.users_to_remove,
+            });
+
+            if let Some(friends_allowed) = update.friends_allowed {
+                json["friendsAllowed"] = friends_allowed.into();
+            }
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<PrivateServerPermissionsRaw>(response).await?;
+
+            Ok(raw.into())
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{GameServer, ServerType, SortOrder};
+use crate::client::{RobloxApi, RobloxError};
+
+/// Ping at or above which a server gets no ping score.
+const WORST_PING: f32 = 300.0;
+
+/// Server fps at or above which a server gets the full fps score.
+const BEST_FPS: f32 = 60.0;
+
+const PING_WEIGHT: f32 = 0.4;
+const FPS_WEIGHT: f32 = 0.3;
+const FILL_WEIGHT: f32 = 0.3;
+
+/// Which servers are preferred by player count.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FillPreference {
+    /// Half full servers score the best.
+    #[default]
+    Balanced,
+    NearlyFull,
+    Empty,
+}
+
+/// Options of [`RobloxApi::ranked_game_servers`].
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ServerRankingOptions {
+    /// How many pages of 100 servers are scanned.
+    pub pages: usize,
+    pub fill_preference: FillPreference,
+
+    /// Servers which are never picked, e.g. ones the user was just in.
+    pub exclude_server_ids: Vec<String>,
+}
+
+impl Default for ServerRankingOptions {
+    fn default() -> Self {
+        Self {
+            pages: 3,
+            fill_preference: FillPreference::default(),
+            exclude_server_ids: Vec::new(),
+        }
+    }
+}
+
+/// A server with its score, from 0 (worst) to 1 (best).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct RankedServer {
+    pub server: GameServer,
+    pub score: f32,
+}
+
+impl RobloxApi {
+    /// Scans public servers of a place and ranks them by ping, fps and player count.
+    /// Full servers are never returned. The best server is first.
+    pub async fn ranked_game_servers(
+        &self,
+        place_id: u64,
+        options: &ServerRankingOptions,
+    ) -> Result<Vec<RankedServer>, RobloxError> {
+        let servers = self
+            .all_game_servers(
+                place_id,
+                Some(ServerType::Public),
+                Some(SortOrder::Descending),
+                Some(true),
+                Some(options.pages.max(1)),
+            )
+            .await?;
+
+        Ok(rank_servers(servers, options))
+    }
+}
+
+/// Scores and sorts servers, best first.
+pub fn rank_servers(servers: Vec<GameServer>, options: &ServerRankingOptions) -> Vec<RankedServer> {
+    let excluded = options.exclude_server_ids.iter().collect::<HashSet<_>>();
+
+    let mut ranked = servers
+        .into_iter()
+        .filter(|server| server.playing < server.max_players && !excluded.contains(&server.id))
+        .map(|server| RankedServer {
+            score: score(&server, options.fill_preference),
+            server,
+        })
+        .collect::<Vec<_>>();
+
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
+
+fn score(server: &GameServer, fill_preference: FillPreference) -> f32 {
+    let ping_score = 1.0 - (server.ping as f32 / WORST_PING).clamp(0.0, 1.0);
+    let fps_score = (server.fps / BEST_FPS).clamp(0.0, 1.0);
+
+    let fill_ratio = if server.max_players == 0 {
+        0.0
+    } else {
+        server.playing as f32 / server.max_players as f32
+    };
+
+    let fill_score = match fill_preference {
+        FillPreference::Balanced => 1.0 - (fill_ratio - 0.5).abs() * 2.0,
+        FillPreference::NearlyFull => fill_ratio,
+        FillPreference::Empty => 1.0 - fill_ratio,
+    };
+
+    ping_score * PING_WEIGHT + fps_score * FPS_WEIGHT + fill_score * FILL_WEIGHT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rank_servers, score, FillPreference, GameServer, ServerRankingOptions};
+
+    fn server(id: &str, playing: usize, ping: u64) -> GameServer {
+        GameServer {
+            id: id.to_string(),
+            max_players: 10,
+            playing,
+            fps: 60.0,
+            ping,
+            ..Default::default()
+        }
+    }
+
+    fn ranked_ids(servers: Vec<GameServer>, options: &ServerRankingOptions) -> Vec<String> {
+        rank_servers(servers, options)
+            .into_iter()
+            .map(|ranked| ranked.server.id)
+            .collect()
+    }
+
+    #[test]
+    fn lower_ping_ranks_first() {
+        let servers = vec![
+            server("slow", 5, 250),
+            server("fast", 5, 20),
+            server("medium", 5, 120),
+        ];
+
+        assert_eq!(
+            ranked_ids(servers, &ServerRankingOptions::default()),
+            vec!["fast", "medium", "slow"]
+        );
+    }
+
+    #[test]
+    fn fill_preference_orders_by_player_count() {
+        let servers = || {
+            vec![
+                server("empty", 1, 50),
+                server("half", 5, 50),
+                server("nearly-full", 9, 50),
+            ]
+        };
+        let options = |fill_preference| ServerRankingOptions {
+            fill_preference,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ranked_ids(servers(), &options(FillPreference::Balanced))[0],
+            "half"
+        );
+        assert_eq!(
+            ranked_ids(servers(), &options(FillPreference::NearlyFull)),
+            vec!["nearly-full", "half", "empty"]
+        );
+        assert_eq!(
+            ranked_ids(servers(), &options(FillPreference::Empty)),
+            vec!["empty", "half", "nearly-full"]
+        );
+    }
+
+    #[test]
+    fn recent_and_full_servers_are_never_picked() {
+        let servers = vec![
+            server("recent", 5, 10),
+            server("full", 10, 10),
+            server("other", 5, 200),
+        ];
+        let options = ServerRankingOptions {
+            exclude_server_ids: vec!["recent".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(ranked_ids(servers, &options), vec!["other"]);
+    }
+
+    #[test]
+    fn score_stays_within_bounds() {
+        let best = score(&server("best", 5, 0), FillPreference::Balanced);
+        let worst = GameServer {
+            fps: 0.0,
+            max_players: 0,
+            ..server("worst", 0, 1000)
+        };
+
+        assert_eq!(best, 1.0);
+        assert_eq!(score(&worst, FillPreference::NearlyFull), 0.0);
+    }
+}
@@ -115,11 +115,119 @@ pub(super) struct GameServersResponse {
 pub(super) struct GameServerRaw {
     pub id: String,
     pub max_players: usize,
+    #[serde(default)]
     pub playing: usize,
 
+    #[serde(default)]
     pub player_tokens: Vec<String>,
 
-    // pub players: Vec<String>,
+    /// Only filled in for friends servers.
+    #[serde(default)]
+    pub players: Vec<GameServerPlayerRaw>,
+
+    #[serde(default)]
     pub fps: f32,
+    #[serde(default)]
     pub ping: u64,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GameServerPlayerRaw {
+    pub player_token: String,
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServersResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<PrivateServerRaw>,
+}
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerRaw {
+    /// Only set while the server is running.
+    pub id: Option<String>,
+    pub vip_server_id: u64,
+    pub name: String,
+    pub access_code: String,
+    pub owner: PrivateServerUserRaw,
+
+    pub max_players: usize,
+    #[serde(default)]
+    pub playing: usize,
+    #[serde(default)]
+    pub player_tokens: Vec<String>,
+
+    #[serde(default)]
+    pub fps: f32,
+    #[serde(default)]
+    pub ping: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerUserRaw {
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerOptionsRaw {
+    #[serde(default)]
+    pub is_enabled: bool,
+    pub price: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerDetailsRaw {
+    pub id: u64,
+    pub name: String,
+    pub game: PrivateServerGameRaw,
+    pub active: bool,
+
+    pub join_code: Option<String>,
+    pub link: Option<String>,
+
+    pub subscription: PrivateServerSubscriptionRaw,
+    pub permissions: PrivateServerPermissionsRaw,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerGameRaw {
+    pub id: u64,
+    pub root_place: PrivateServerPlaceRaw,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerPlaceRaw {
+    pub id: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerSubscriptionRaw {
+    pub active: bool,
+    pub expired: bool,
+    pub expiration_date: Option<String>,
+    pub price: Option<u64>,
+    pub can_renew: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PrivateServerPermissionsRaw {
+    pub friends_allowed: bool,
+    #[serde(default)]
+    pub users: Vec<PrivateServerUserRaw>,
+}
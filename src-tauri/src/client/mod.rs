@@ -3,6 +3,7 @@
 mod client;
 mod validation;
 
+pub mod blocking;
 pub mod discovery;
 pub mod economy;
 pub mod friends;
@@ -108,6 +109,11 @@ pub enum RobloxError {
     #[error("{0}")]
     PurchaseTradableLimitedError(PurchaseTradableLimitedError),
 
+    /// Custom Roblox errors thrown when the user calls [`RobloxApi::set_display_name`]
+    /// or [`RobloxApi::validate_display_name`].
+    #[error("{0}")]
+    DisplayNameError(users::DisplayNameError),
+
     // /// Custom Roblox errors sometimes thrown when the user calls [`Client::purchase_non_tradable_limited`].
     // #[error("{0}")]
     // PurchaseNonTradableLimitedError(PurchaseNonTradableLimitedError),
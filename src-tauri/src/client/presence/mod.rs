@@ -12,6 +12,7 @@ const GET_PRESENCE_API: &str = "https://presence.roblox.com/v1/presence/users";
 /// Presence of user
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum PresenceType {
     #[default]
     Offline,
@@ -19,19 +20,76 @@ pub enum PresenceType {
     InGame,
     InStudio,
     Invisible,
-}
 
-impl TryFrom<i32> for PresenceType {
-    type Error = RobloxError;
+    /// Presence type unknown to rsblox, with the raw value sent by Roblox.
+    Unknown(String),
+}
 
-    fn try_from(v: i32) -> Result<Self, Self::Error> {
+impl From<i32> for PresenceType {
+    fn from(v: i32) -> Self {
         match v {
-            0 => Ok(Self::Offline),
-            1 => Ok(Self::Online),
-            2 => Ok(Self::InGame),
-            3 => Ok(Self::InStudio),
-            4 => Ok(Self::Invisible),
-            _ => Err(RobloxError::MalformedResponse),
+            0 => Self::Offline,
+            1 => Self::Online,
+            2 => Self::InGame,
+            3 => Self::InStudio,
+            4 => Self::Invisible,
+            _ => Self::Unknown(v.to_string()),
+        }
+    }
+}
+
+impl From<String> for PresenceType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Offline" => Self::Offline,
+            "Online" => Self::Online,
+            "InGame" => Self::InGame,
+            "InStudio" => Self::InStudio,
+            "Invisible" => Self::Invisible,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<PresenceType> for String {
+    fn from(value: PresenceType) -> Self {
+        match value {
+            PresenceType::Offline => "Offline".to_string(),
+            PresenceType::Online => "Online".to_string(),
+            PresenceType::InGame => "InGame".to_string(),
+            PresenceType::InStudio => "InStudio".to_string(),
+            PresenceType::Invisible => "Invisible".to_string(),
+            PresenceType::Unknown(raw) => raw,
+        }
+    }
+}
+
+/// Where in the app the user is, as reported by [`RobloxApi::register_presence`].
+#[allow(missing_docs)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum PresenceLocation {
+    #[default]
+    Home,
+    Games,
+    Avatar,
+    Catalog,
+    Friends,
+    Profile,
+    Groups,
+}
+
+impl PresenceLocation {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Home => "Home",
+            Self::Games => "Games",
+            Self::Avatar => "Avatar",
+            Self::Catalog => "Catalog",
+            Self::Friends => "Friends",
+            Self::Profile => "Profile",
+            Self::Groups => "Groups",
         }
     }
 }
@@ -54,14 +112,14 @@ pub struct UserPresence {
 impl RobloxApi {
     /// Registers presence on the website (makes you appear to be online). Endpoint called is
     /// <https://presence.roblox.com/v1/presence/register-app-presence>
-    pub async fn register_presence(&self) -> Result<(), RobloxError> {
-        match self.register_presence_internal().await {
+    pub async fn register_presence(&self, location: PresenceLocation) -> Result<(), RobloxError> {
+        match self.register_presence_internal(location).await {
             Ok(x) => Ok(x),
             Err(e) => match e {
                 RobloxError::InvalidXcsrf(new_xcsrf) => {
                     self.set_xcsrf(new_xcsrf).await;
 
-                    self.register_presence_internal().await
+                    self.register_presence_internal(location).await
                 }
                 _ => Err(e),
             },
@@ -92,8 +150,7 @@ impl RobloxApi {
         for user_presence in raw.user_presences {
             presences.push(UserPresence {
                 user_id: user_presence.user_id,
-                presence_type: PresenceType::try_from(user_presence.user_presence_type)
-                    .map_err(|_| RobloxError::MalformedResponse)?,
+                presence_type: PresenceType::from(user_presence.user_presence_type),
                 last_online: user_presence.last_online,
                 last_location: user_presence.last_location,
                 place_id: user_presence.place_id,
@@ -109,15 +166,18 @@ impl RobloxApi {
 mod internal {
     use reqwest::header;
 
-    use super::REGISTER_PRESENCE_API;
+    use super::{PresenceLocation, REGISTER_PRESENCE_API};
     use crate::client::{RobloxApi, RobloxError, XCSRF_HEADER};
 
     impl RobloxApi {
-        pub(super) async fn register_presence_internal(&self) -> Result<(), RobloxError> {
+        pub(super) async fn register_presence_internal(
+            &self,
+            location: PresenceLocation,
+        ) -> Result<(), RobloxError> {
             let cookie = self.cookie_string().await?;
 
             let json = serde_json::json!({
-                "location": "Home",
+                "location": location.as_str(),
             });
 
             let request_result = self
@@ -136,3 +196,49 @@ mod internal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PresenceType;
+
+    #[test]
+    fn presence_type_from_known_code() {
+        assert_eq!(PresenceType::from(0), PresenceType::Offline);
+        assert_eq!(PresenceType::from(2), PresenceType::InGame);
+        assert_eq!(PresenceType::from(4), PresenceType::Invisible);
+    }
+
+    #[test]
+    fn presence_type_from_unknown_code() {
+        assert_eq!(
+            PresenceType::from(7),
+            PresenceType::Unknown("7".to_string())
+        );
+    }
+
+    #[test]
+    fn presence_type_deserializes_unknown_value() {
+        let presence_types =
+            serde_json::from_str::<Vec<PresenceType>>(r#"["InStudio", "InParty"]"#).unwrap();
+
+        assert_eq!(
+            presence_types,
+            vec![
+                PresenceType::InStudio,
+                PresenceType::Unknown("InParty".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn presence_type_serializes_as_raw_string() {
+        assert_eq!(
+            serde_json::to_string(&PresenceType::InGame).unwrap(),
+            r#""InGame""#
+        );
+        assert_eq!(
+            serde_json::to_string(&PresenceType::Unknown("InParty".to_string())).unwrap(),
+            r#""InParty""#
+        );
+    }
+}
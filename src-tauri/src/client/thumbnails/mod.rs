@@ -8,6 +8,12 @@ mod request_types;
 
 const THUMBNAIL_API_URL: &str = "https://thumbnails.roblox.com/v1/batch";
 
+/// The maximum amount of items sent in a single thumbnails batch request.
+pub const THUMBNAILS_CHUNK_SIZE: usize = 100;
+
+/// Headshot size used wherever headshots are compared by url, as urls differ between sizes.
+pub const HEADSHOT_SIZE: ThumbnailSize = ThumbnailSize::S150x150;
+
 /// A size for an asset thumbnail.
 ///
 /// Sizes are taken from <https://thumbnails.roblox.com/docs/index.html#operations-Assets-get_v1_assets>.
@@ -97,6 +103,44 @@ impl RobloxApi {
         size: ThumbnailSize,
         thumbnail_type: ThumbnailType,
     ) -> Result<Vec<String>, RobloxError> {
+        let datas = self.thumbnail_datas_bulk(ids, size, thumbnail_type).await?;
+
+        Ok(datas
+            .into_iter()
+            .map(|data| data.image_url.unwrap_or_default())
+            .collect())
+    }
+
+    /// Like [`RobloxApi::thumbnail_url_bulk`], but only returns urls of thumbnails Roblox finished
+    /// generating, in the same order as `ids`. Pending, blocked and errored thumbnails are `None`,
+    /// so the urls are safe to compare with each other.
+    pub async fn completed_thumbnail_url_bulk(
+        &self,
+        ids: Vec<u64>,
+        size: ThumbnailSize,
+        thumbnail_type: ThumbnailType,
+    ) -> Result<Vec<Option<String>>, RobloxError> {
+        let datas = self
+            .thumbnail_datas_bulk(ids.clone(), size, thumbnail_type)
+            .await?;
+
+        Ok(ids
+            .iter()
+            .map(|id| {
+                datas
+                    .iter()
+                    .find(|data| data.target_id as u64 == *id)
+                    .and_then(completed_url)
+            })
+            .collect())
+    }
+
+    async fn thumbnail_datas_bulk(
+        &self,
+        ids: Vec<u64>,
+        size: ThumbnailSize,
+        thumbnail_type: ThumbnailType,
+    ) -> Result<Vec<request_types::AssetThumbnailUrlDataRaw>, RobloxError> {
         let mut json_item_requests = Vec::new();
 
         for id in &ids {
@@ -124,13 +168,7 @@ impl RobloxApi {
 
         sort_url_datas_by_id_argument_order(&mut raw.data, &ids);
 
-        let mut urls = Vec::new();
-
-        for data in raw.data {
-            urls.push(data.image_url);
-        }
-
-        Ok(urls)
+        Ok(raw.data)
     }
 
     /// Fetches a thumbnail of a specified size and type using <https://thumbnails.roblox.com/v1/batch>.
@@ -154,6 +192,46 @@ impl RobloxApi {
         size: ThumbnailSize,
         thumbnail_type: ThumbnailType,
     ) -> Result<Vec<String>, RobloxError> {
+        let datas = self
+            .token_thumbnail_datas_bulk(tokens, size, thumbnail_type)
+            .await?;
+
+        Ok(datas
+            .into_iter()
+            .map(|data| data.image_url.unwrap_or_default())
+            .collect())
+    }
+
+    /// Like [`RobloxApi::token_thumbnail_url_bulk`], but only returns urls of thumbnails Roblox
+    /// finished generating, in the same order as `tokens`. Pending, blocked and errored thumbnails
+    /// are `None`, so the urls are safe to compare with each other.
+    pub async fn completed_token_thumbnail_url_bulk(
+        &self,
+        tokens: Vec<String>,
+        size: ThumbnailSize,
+        thumbnail_type: ThumbnailType,
+    ) -> Result<Vec<Option<String>>, RobloxError> {
+        let datas = self
+            .token_thumbnail_datas_bulk(tokens.clone(), size, thumbnail_type)
+            .await?;
+
+        Ok(tokens
+            .iter()
+            .map(|token| {
+                datas
+                    .iter()
+                    .find(|data| data.request_id.split(':').nth(1) == Some(token.as_str()))
+                    .and_then(completed_url)
+            })
+            .collect())
+    }
+
+    async fn token_thumbnail_datas_bulk(
+        &self,
+        tokens: Vec<String>,
+        size: ThumbnailSize,
+        thumbnail_type: ThumbnailType,
+    ) -> Result<Vec<request_types::AssetThumbnailUrlDataRaw>, RobloxError> {
         let mut json_item_requests = Vec::new();
 
         for token in &tokens {
@@ -182,13 +260,7 @@ impl RobloxApi {
 
         sort_url_datas_by_token_argument_order(&mut raw.data, &tokens);
 
-        let mut urls = Vec::new();
-
-        for data in raw.data {
-            urls.push(data.image_url);
-        }
-
-        Ok(urls)
+        Ok(raw.data)
     }
 
     /// Exchanges token to a thumbnail of a specified size and type using <https://thumbnails.roblox.com/v1/batch>.
@@ -206,6 +278,15 @@ impl RobloxApi {
     }
 }
 
+/// Url of the thumbnail, if Roblox finished generating it.
+fn completed_url(data: &request_types::AssetThumbnailUrlDataRaw) -> Option<String> {
+    if data.state != "Completed" {
+        return None;
+    }
+
+    data.image_url.clone().filter(|url| !url.is_empty())
+}
+
 /// Makes sure that the url datas are in the same order as the arguments.
 fn sort_url_datas_by_id_argument_order(
     url_datas: &mut [request_types::AssetThumbnailUrlDataRaw],
@@ -289,3 +370,38 @@ fn generate_thumbnail_type_string(thumbnail_type: ThumbnailType) -> String {
         ThumbnailType::GameThumbnail => "GameThumbnail".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{completed_url, request_types::AssetThumbnailUrlDataRaw};
+
+    fn data(state: &str, image_url: Option<&str>) -> AssetThumbnailUrlDataRaw {
+        AssetThumbnailUrlDataRaw {
+            request_id: "1::AvatarHeadShot:150x150:null:regular".to_string(),
+            error_code: 0,
+            error_message: String::new(),
+            target_id: 1,
+            state: state.to_string(),
+            image_url: image_url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn completed_thumbnails_keep_their_url() {
+        assert_eq!(
+            completed_url(&data("Completed", Some("https://tr.rbxcdn.com/a"))),
+            Some("https://tr.rbxcdn.com/a".to_string())
+        );
+    }
+
+    #[test]
+    fn unfinished_thumbnails_have_no_url() {
+        assert_eq!(completed_url(&data("Pending", Some(""))), None);
+        assert_eq!(completed_url(&data("Pending", None)), None);
+        assert_eq!(
+            completed_url(&data("Blocked", Some("https://tr.rbxcdn.com/blocked"))),
+            None
+        );
+        assert_eq!(completed_url(&data("Completed", Some(""))), None);
+    }
+}
@@ -17,5 +17,5 @@ pub(super) struct AssetThumbnailUrlDataRaw {
     pub target_id: i64,
     pub state: String,
     #[serde(rename = "imageUrl")]
-    pub image_url: String,
+    pub image_url: Option<String>,
 }
@@ -1,4 +1,7 @@
-use super::{client::ClientUserInformation, RobloxApi, RobloxError, User};
+use super::{
+    client::ClientUserInformation, friends::FriendStatus, presence::UserPresence, Limit, RobloxApi,
+    RobloxError,
+};
 use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
 
@@ -8,8 +11,102 @@ const AUTHENTICATED_USER_DETAILS_API: &str = "https://users.roblox.com/v1/users/
 const USERS_SEARCH_API: &str = "https://users.roblox.com/v1/users/search";
 const USER_DETAILS_API: &str = "https://users.roblox.com/v1/users/{user_id}";
 const USER_FROM_USERNAME_API: &str = "https://users.roblox.com/v1/usernames/users";
+const USERS_DETAILS_API: &str = "https://users.roblox.com/v1/users";
+const USERNAME_HISTORY_API: &str =
+    "https://users.roblox.com/v1/users/{user_id}/username-history?limit={limit}&sortOrder=Desc";
+const ROBLOX_BADGES_API: &str =
+    "https://accountinformation.roblox.com/v1/users/{user_id}/roblox-badges";
 
-// TODO: try to make a unified user details struct
+const DESCRIPTION_API: &str = "https://users.roblox.com/v1/description";
+const DISPLAY_NAMES_API: &str = "https://users.roblox.com/v1/users/{user_id}/display-names";
+const VALIDATE_DISPLAY_NAME_API: &str =
+    "https://users.roblox.com/v1/users/{user_id}/display-names/validate";
+const PROMOTION_CHANNELS_API: &str =
+    "https://accountinformation.roblox.com/v1/promotion-channels?alwaysReturnUrls=true";
+
+/// The maximum amount of user ids sent in a single users details request.
+const USERS_DETAILS_CHUNK_SIZE: usize = 100;
+
+/// Custom Roblox errors that occur when using [`RobloxApi::set_display_name`] or
+/// [`RobloxApi::validate_display_name`].
+#[non_exhaustive]
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum DisplayNameError {
+    /// Roblox error code 1.
+    #[default]
+    #[error("Display name is too short.")]
+    TooShort,
+    /// Roblox error code 2.
+    #[error("Display name is too long.")]
+    TooLong,
+    /// Roblox error code 3.
+    #[error("Display name contains invalid characters.")]
+    InvalidCharacters,
+    /// Roblox error code 4. There is no point in retrying with the same name.
+    #[error("Display name has been moderated.")]
+    Moderated,
+    /// Roblox error code 5. Display names can only be changed once per week.
+    #[error("Display name updates for this user have been throttled.")]
+    Throttled,
+    /// Thrown when Roblox returns an error code that is not known yet.
+    #[error("Unknown Roblox Error Message: {0}")]
+    UnknownRobloxErrorMsg(String),
+}
+
+impl DisplayNameError {
+    /// Converts Roblox errors returned by the display name endpoints into
+    /// [`RobloxError::DisplayNameError`]. Other errors are passed through.
+    fn map_roblox_error(error: RobloxError) -> RobloxError {
+        match error {
+            RobloxError::UnknownRobloxErrorCode { code, message } => {
+                RobloxError::DisplayNameError(match code {
+                    1 => Self::TooShort,
+                    2 => Self::TooLong,
+                    3 => Self::InvalidCharacters,
+                    4 => Self::Moderated,
+                    5 => Self::Throttled,
+                    _ => Self::UnknownRobloxErrorMsg(message),
+                })
+            }
+            _ => error,
+        }
+    }
+}
+
+/// Social links of the logged in user. Fetched from
+/// <https://accountinformation.roblox.com/v1/promotion-channels>.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PromotionChannels {
+    /// Who can see the links.
+    ///
+    /// Can be:
+    ///  - "NoOne",
+    ///  - "Friends",
+    ///  - "FriendsAndFollowing",
+    ///  - "FriendsFollowingAndFollowers",
+    ///  - "AllAuthenticatedUsers",
+    ///  - "AllUsers"
+    pub visibility_privacy: String,
+
+    pub facebook: Option<String>,
+    pub twitter: Option<String>,
+    pub youtube: Option<String>,
+    pub twitch: Option<String>,
+    pub guilded: Option<String>,
+}
 
 /// The details of a user. Fetched from <https://users.roblox.com/v1/users/{user_id}>.
 #[allow(missing_docs)]
@@ -45,6 +142,78 @@ pub struct UsernameUserDetails {
     pub has_verified_badge: bool,
 }
 
+/// The details of a user. Fetched from <https://users.roblox.com/v1/users>.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserIdUserDetails {
+    #[serde(alias = "name")]
+    pub username: String,
+    #[serde(alias = "displayName")]
+    pub display_name: String,
+    pub id: u64,
+    #[serde(alias = "hasVerifiedBadge")]
+    pub has_verified_badge: bool,
+}
+
+/// A user returned by <https://users.roblox.com/v1/users/search>.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserSearchResult {
+    #[serde(alias = "id")]
+    pub user_id: u64,
+    #[serde(alias = "name")]
+    pub username: String,
+    #[serde(alias = "displayName")]
+    pub display_name: String,
+    #[serde(alias = "hasVerifiedBadge")]
+    pub has_verified_badge: bool,
+    /// Previous usernames that matched the keyword.
+    #[serde(alias = "previousUsernames")]
+    pub previous_usernames: Vec<String>,
+}
+
+/// Friends, followers and followings counts of a user.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub struct UserProfileStats {
+    pub friends: usize,
+    pub followers: usize,
+    pub followings: usize,
+}
+
+/// A Roblox badge (Veteran, Friendship, etc.) of a user. Fetched from
+/// <https://accountinformation.roblox.com/v1/users/{user_id}/roblox-badges>.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct RobloxBadge {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+}
+
+/// Everything the user page shows about a user, assembled by [`RobloxApi::user_profile`].
+/// The [`UserDetails`] fields are flattened into it when serialized.
+///
+/// Optional parts are `None` when they could not be fetched
+/// (e.g. friendship status without being logged in).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserProfile {
+    #[serde(flatten)]
+    pub details: UserDetails,
+
+    pub presence: Option<UserPresence>,
+    pub stats: Option<UserProfileStats>,
+    pub badges: Option<Vec<RobloxBadge>>,
+    pub friend_status: Option<FriendStatus>,
+
+    /// Up to 100 most recent previous usernames, newest first.
+    pub previous_usernames: Option<Vec<String>>,
+}
+
 impl RobloxApi {
     /// Grabs information about the user from <https://catalog.roblox.com/v1/catalog/items/details> using the
     /// Roblosecurity inside the client.
@@ -69,9 +238,22 @@ impl RobloxApi {
         Ok(user_information)
     }
 
-    /// Searches for a user using <https://users.roblox.com/v1/users/search>.
-    pub async fn user_search(&self, keyword: String) -> Result<Vec<User>, RobloxError> {
-        let formatted_url = format!("{}?keyword={}", USERS_SEARCH_API, keyword);
+    /// Searches for users with cursor using <https://users.roblox.com/v1/users/search>.
+    ///
+    /// If `exclude_blocked` is set, users blocked by the logged in user are filtered out.
+    pub async fn user_search(
+        &self,
+        keyword: String,
+        limit: Limit,
+        cursor: Option<String>,
+        exclude_blocked: bool,
+    ) -> Result<(Vec<UserSearchResult>, Option<String>), RobloxError> {
+        // Passed as query parameters, so keywords and cursors are properly encoded.
+        let mut query = vec![("keyword", keyword), ("limit", limit.to_u64().to_string())];
+
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor));
+        }
 
         let cookie_string = self
             .cookie_string()
@@ -80,7 +262,8 @@ impl RobloxApi {
 
         let request_result = self
             .reqwest_client
-            .get(formatted_url)
+            .get(USERS_SEARCH_API)
+            .query(&query)
             .header(header::COOKIE, cookie_string)
             .send()
             .await;
@@ -91,16 +274,24 @@ impl RobloxApi {
         let mut users = Vec::new();
 
         for user in raw.data {
-            let user_data = User {
+            let user_data = UserSearchResult {
                 user_id: user.id,
                 username: user.name,
                 display_name: user.display_name,
+                has_verified_badge: user.has_verified_badge,
+                previous_usernames: user.previous_usernames,
             };
 
             users.push(user_data);
         }
 
-        Ok(users)
+        if exclude_blocked {
+            let blocked_user_ids = self.blocked_user_ids().await?;
+
+            users.retain(|user| !blocked_user_ids.contains(&user.user_id));
+        }
+
+        Ok((users, raw.next_page_cursor))
     }
 
     /// Fetches user details using <https://users.roblox.com/v1/users/{user_id}>.
@@ -148,4 +339,334 @@ impl RobloxApi {
             .collect();
         Ok(users)
     }
+
+    /// Fetches details of multiple users using <https://users.roblox.com/v1/users>.
+    /// Users that don't exist (or are banned, if `exclude_banned_users` is set) are left out.
+    pub async fn users_details(
+        &self,
+        user_ids: Vec<u64>,
+        exclude_banned_users: bool,
+    ) -> Result<Vec<UserIdUserDetails>, RobloxError> {
+        let mut users = Vec::new();
+
+        for chunk in user_ids.chunks(USERS_DETAILS_CHUNK_SIZE) {
+            let request_result = self
+                .reqwest_client
+                .post(USERS_DETAILS_API)
+                .json(&request_types::UsersDetailsRequest {
+                    user_ids: chunk.to_vec(),
+                    exclude_banned_users,
+                })
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::UsersDetailsResponse>(response).await?;
+
+            users.extend(raw.data.into_iter().map(|user| UserIdUserDetails {
+                username: user.name,
+                display_name: user.display_name,
+                id: user.id,
+                has_verified_badge: user.has_verified_badge,
+            }));
+        }
+
+        Ok(users)
+    }
+
+    /// Fetches previous usernames of a user (newest first) with cursor using
+    /// <https://users.roblox.com/v1/users/{user_id}/username-history>.
+    pub async fn username_history(
+        &self,
+        user_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<String>, Option<String>), RobloxError> {
+        let mut formatted_url = USERNAME_HISTORY_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{limit}", &limit.to_u64().to_string());
+
+        if let Some(cursor) = cursor {
+            formatted_url = format!("{}&cursor={}", formatted_url, cursor);
+        }
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::UsernameHistoryResponse>(response).await?;
+
+        let usernames = raw.data.into_iter().map(|entry| entry.name).collect();
+
+        Ok((usernames, raw.next_page_cursor))
+    }
+
+    /// Fetches Roblox badges of a user using <https://accountinformation.roblox.com/v1/users/{user_id}/roblox-badges>.
+    pub async fn roblox_badges(&self, user_id: u64) -> Result<Vec<RobloxBadge>, RobloxError> {
+        let formatted_url = ROBLOX_BADGES_API.replace("{user_id}", &user_id.to_string());
+
+        let request_result = self.reqwest_client.get(formatted_url).send().await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<Vec<request_types::RobloxBadgeRaw>>(response).await?;
+
+        let badges = raw
+            .into_iter()
+            .map(|badge| RobloxBadge {
+                id: badge.id,
+                name: badge.name,
+                description: badge.description,
+                image_url: badge.image_url,
+            })
+            .collect();
+
+        Ok(badges)
+    }
+
+    /// Fetches friends, followers and followings counts of a user concurrently.
+    pub async fn user_profile_stats(&self, user_id: u64) -> Result<UserProfileStats, RobloxError> {
+        let (friends, followers, followings) = tokio::try_join!(
+            self.friends_count(user_id),
+            self.followers_count(user_id),
+            self.followings_count(user_id),
+        )?;
+
+        Ok(UserProfileStats {
+            friends,
+            followers,
+            followings,
+        })
+    }
+
+    /// Assembles a [`UserProfile`] by fetching user details, presence, stats, badges,
+    /// friendship status and username history concurrently. Only a failure to fetch the details is returned as an error.
+    pub async fn user_profile(&self, user_id: u64) -> Result<UserProfile, RobloxError> {
+        let is_me = self.user_id().await.is_ok_and(|id| id == user_id);
+
+        let (details, presence, stats, badges, friend_status, username_history) = tokio::join!(
+            self.user_details(user_id),
+            self.get_presence(vec![user_id]),
+            self.user_profile_stats(user_id),
+            self.roblox_badges(user_id),
+            async {
+                if is_me {
+                    return None;
+                }
+
+                self.friend_status(user_id).await.ok()
+            },
+            self.username_history(user_id, Limit::Hundred, None),
+        );
+
+        Ok(UserProfile {
+            details: details?,
+            presence: presence
+                .ok()
+                .and_then(|presences| presences.into_iter().next()),
+            stats: stats.ok(),
+            badges: badges.ok(),
+            friend_status,
+            previous_usernames: username_history.ok().map(|(usernames, _)| usernames),
+        })
+    }
+
+    /// Updates the About description of the logged in user using <https://users.roblox.com/v1/description>.
+    /// Returns the description after Roblox filtered it.
+    pub async fn set_description(&self, description: String) -> Result<String, RobloxError> {
+        match self.set_description_internal(description.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_description_internal(description).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Checks whether the logged in user can change their display name to `display_name` using
+    /// <https://users.roblox.com/v1/users/{user_id}/display-names/validate>.
+    ///
+    /// Returns [`RobloxError::DisplayNameError`] if the display name is rejected.
+    pub async fn validate_display_name(&self, display_name: String) -> Result<(), RobloxError> {
+        let formatted_url =
+            VALIDATE_DISPLAY_NAME_API.replace("{user_id}", &self.user_id().await?.to_string());
+
+        let cookie = self.cookie_string().await?;
+
+        // Passed as a query parameter, so names with spaces or symbols are properly encoded.
+        let request_result = self
+            .reqwest_client
+            .get(formatted_url)
+            .query(&[("displayName", display_name)])
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        Self::validate_request_result(request_result)
+            .await
+            .map_err(DisplayNameError::map_roblox_error)?;
+
+        Ok(())
+    }
+
+    /// Changes the display name of the logged in user using <https://users.roblox.com/v1/users/{user_id}/display-names>.
+    ///
+    /// Returns [`RobloxError::DisplayNameError`] if the display name is rejected.
+    pub async fn set_display_name(&self, display_name: String) -> Result<(), RobloxError> {
+        let result = match self.set_display_name_internal(display_name.clone()).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_display_name_internal(display_name).await
+                }
+                _ => Err(e),
+            },
+        };
+
+        result.map_err(DisplayNameError::map_roblox_error)
+    }
+
+    /// Gets social links of the logged in user using <https://accountinformation.roblox.com/v1/promotion-channels>.
+    pub async fn promotion_channels(&self) -> Result<PromotionChannels, RobloxError> {
+        let cookie = self.cookie_string().await?;
+
+        let request_result = self
+            .reqwest_client
+            .get(PROMOTION_CHANNELS_API)
+            .header(header::COOKIE, cookie)
+            .send()
+            .await;
+
+        let response = Self::validate_request_result(request_result).await?;
+        let raw = Self::parse_to_raw::<request_types::PromotionChannelsRaw>(response).await?;
+
+        Ok(PromotionChannels {
+            visibility_privacy: raw.promotion_channels_visibility_privacy,
+            facebook: raw.facebook,
+            twitter: raw.twitter,
+            youtube: raw.youtube,
+            twitch: raw.twitch,
+            guilded: raw.guilded,
+        })
+    }
+
+    /// Updates social links of the logged in user using <https://accountinformation.roblox.com/v1/promotion-channels>.
+    pub async fn set_promotion_channels(
+        &self,
+        promotion_channels: PromotionChannels,
+    ) -> Result<(), RobloxError> {
+        match self
+            .set_promotion_channels_internal(promotion_channels.clone())
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RobloxError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_promotion_channels_internal(promotion_channels)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use reqwest::header;
+
+    use super::{request_types, PromotionChannels};
+    use crate::client::{RobloxApi, RobloxError, XCSRF_HEADER};
+
+    impl RobloxApi {
+        pub(super) async fn set_description_internal(
+            &self,
+            description: String,
+        ) -> Result<String, RobloxError> {
+            let cookie = self.cookie_string().await?;
+
+            let json = serde_json::json!({
+                "description": description,
+            });
+
+            let request_result = self
+                .reqwest_client
+                .post(super::DESCRIPTION_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let response = Self::validate_request_result(request_result).await?;
+            let raw = Self::parse_to_raw::<request_types::DescriptionResponse>(response).await?;
+
+            Ok(raw.description)
+        }
+
+        pub(super) async fn set_display_name_internal(
+            &self,
+            display_name: String,
+        ) -> Result<(), RobloxError> {
+            let formatted_url =
+                super::DISPLAY_NAMES_API.replace("{user_id}", &self.user_id().await?.to_string());
+
+            let cookie = self.cookie_string().await?;
+
+            let json = serde_json::json!({
+                "newDisplayName": display_name,
+            });
+
+            let request_result = self
+                .reqwest_client
+                .patch(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_promotion_channels_internal(
+            &self,
+            promotion_channels: PromotionChannels,
+        ) -> Result<(), RobloxError> {
+            let cookie = self.cookie_string().await?;
+
+            let json = serde_json::json!({
+                "promotionChannelsVisibilityPrivacy": promotion_channels.visibility_privacy,
+                "facebook": promotion_channels.facebook.unwrap_or_default(),
+                "twitter": promotion_channels.twitter.unwrap_or_default(),
+                "youtube": promotion_channels.youtube.unwrap_or_default(),
+                "twitch": promotion_channels.twitch.unwrap_or_default(),
+                "guilded": promotion_channels.guilded.unwrap_or_default(),
+            });
+
+            let request_result = self
+                .reqwest_client
+                .post(super::PROMOTION_CHANNELS_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json)
+                .send()
+                .await;
+
+            let _ = Self::validate_request_result(request_result).await?;
+
+            // If we got a status code 200, it was successful.
+
+            Ok(())
+        }
+    }
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "camelCase")]
 pub struct UserSearchResponse {
     pub previous_page_cursor: Option<String>,
-    pub next_page_cursor: String,
+    pub next_page_cursor: Option<String>,
     pub data: Vec<UserSearchUserInformationRaw>,
 }
 
@@ -38,3 +38,65 @@ pub struct UsernameUserInformationRaw {
 pub struct UsernameUserDetailsResponse {
     pub data: Vec<UsernameUserInformationRaw>,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsersDetailsRequest {
+    pub user_ids: Vec<u64>,
+    pub exclude_banned_users: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserIdUserInformationRaw {
+    pub has_verified_badge: bool,
+    pub id: u64,
+    pub name: String,
+    pub display_name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsersDetailsResponse {
+    pub data: Vec<UserIdUserInformationRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsernameHistoryResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<UsernameHistoryEntryRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsernameHistoryEntryRaw {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RobloxBadgeRaw {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DescriptionResponse {
+    pub description: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromotionChannelsRaw {
+    pub promotion_channels_visibility_privacy: String,
+    pub facebook: Option<String>,
+    pub twitter: Option<String>,
+    pub youtube: Option<String>,
+    pub twitch: Option<String>,
+    pub guilded: Option<String>,
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{client::friends::FriendNote, storage, types::RobloxApiState};
+
+#[tauri::command(async)]
+pub async fn friend_notes<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+) -> Result<HashMap<u64, FriendNote>, String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    Ok(storage::friend_notes::load(&app, account_id))
+}
+
+#[tauri::command(async)]
+pub async fn set_friend_note<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    note: FriendNote,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    storage::friend_notes::set(&app, account_id, user_id, note)
+}
+
+#[tauri::command(async)]
+pub async fn set_friend_pinned<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    pinned: bool,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    let note = storage::friend_notes::load(&app, account_id)
+        .remove(&user_id)
+        .unwrap_or_default();
+
+    storage::friend_notes::set(&app, account_id, user_id, FriendNote { pinned, ..note })
+}
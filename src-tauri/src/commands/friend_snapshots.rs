@@ -0,0 +1,76 @@
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{
+    services::{
+        friend_export::{self, ExportFormat, ExportList},
+        friend_snapshots,
+    },
+    storage::{
+        self,
+        friend_snapshots::{FriendsDiff, FriendsSnapshot},
+    },
+    types::RobloxApiState,
+};
+
+#[tauri::command(async)]
+pub async fn export_friends(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    list: ExportList,
+    format: ExportFormat,
+) -> Result<String, String> {
+    let client = state.0.read().await;
+
+    let users = friend_export::fetch(&client, user_id, list)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    friend_export::format(&users, format)
+}
+
+#[tauri::command(async)]
+pub async fn friends_snapshots<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+) -> Result<Vec<FriendsSnapshot>, String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    Ok(storage::friend_snapshots::load(&app, account_id))
+}
+
+#[tauri::command(async)]
+pub async fn take_friends_snapshot<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+) -> Result<Option<FriendsDiff>, String> {
+    let account_id = {
+        let client = state.0.read().await;
+
+        client.user_id().await.map_err(|err| err.to_string())?
+    };
+
+    friend_snapshots::snapshot_now(&app, account_id).await
+}
+
+/// Compares the current friends list against the latest snapshot without storing a new one.
+#[tauri::command(async)]
+pub async fn friends_snapshot_diff<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+) -> Result<Option<FriendsDiff>, String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    let Some(previous) = storage::friend_snapshots::latest(&app, account_id) else {
+        return Ok(None);
+    };
+
+    let current = friend_snapshots::take(&client, account_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(Some(
+        friend_snapshots::compare(&client, &previous, &current).await,
+    ))
+}
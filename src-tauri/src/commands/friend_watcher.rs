@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{storage::preferences, types::FriendWatcherState};
+
+/// The minimum polling interval, so the watcher doesn't get rate limited.
+const MIN_INTERVAL_SECS: u64 = 5;
+
+#[tauri::command(async)]
+pub async fn friend_watcher_interval(state: State<'_, FriendWatcherState>) -> Result<u64, ()> {
+    Ok(state.interval.read().await.as_secs())
+}
+
+/// The interval is saved, so it's kept after a restart.
+#[tauri::command(async)]
+pub async fn set_friend_watcher_interval<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, FriendWatcherState>,
+    interval_secs: u64,
+) -> Result<(), String> {
+    let interval = Duration::from_secs(interval_secs.max(MIN_INTERVAL_SECS));
+
+    preferences::set_friend_watcher_interval(&app, interval)?;
+    *state.interval.write().await = interval;
+
+    Ok(())
+}
@@ -1,4 +1,12 @@
-use tauri::{api, AppHandle, Manager, Runtime};
+use tauri::{api, AppHandle, Manager, Runtime, State};
+
+use crate::{
+    client::{
+        games::{RankedServer, ServerRankingOptions},
+        presence::PresenceType,
+    },
+    types::{RecentServersState, RobloxApiState},
+};
 
 #[tauri::command]
 pub fn open_place<R: Runtime>(app: AppHandle<R>, place_id: u64) -> Result<(), String> {
@@ -13,9 +21,12 @@ pub fn open_place<R: Runtime>(app: AppHandle<R>, place_id: u64) -> Result<(), St
 #[tauri::command]
 pub fn open_server<R: Runtime>(
     app: AppHandle<R>,
+    recent_servers: State<'_, RecentServersState>,
     place_id: u64,
     game_id: String,
 ) -> Result<(), String> {
+    recent_servers.record(game_id.clone());
+
     api::shell::open(
         &app.shell_scope(),
         format!(
@@ -26,3 +37,119 @@ pub fn open_server<R: Runtime>(
     )
     .map_err(|err| err.to_string())
 }
+
+/// Joins a private server using its access code, see [`crate::client::RobloxApi::private_servers`].
+#[tauri::command]
+pub fn open_private_server<R: Runtime>(
+    app: AppHandle<R>,
+    place_id: u64,
+    access_code: String,
+) -> Result<(), String> {
+    api::shell::open(
+        &app.shell_scope(),
+        format!(
+            "roblox://experiences/start?placeId={}&accessCode={}",
+            place_id, access_code
+        ),
+        None,
+    )
+    .map_err(|err| err.to_string())
+}
+
+/// Joins the server the user is currently in. If the server is hidden by the user's
+/// privacy settings, Roblox is asked to follow the user instead.
+#[tauri::command(async)]
+pub async fn join_user<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    recent_servers: State<'_, RecentServersState>,
+    user_id: u64,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    let presence = client
+        .get_presence(vec![user_id])
+        .await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .next()
+        .ok_or("User presence not found")?;
+
+    if presence.presence_type != PresenceType::InGame {
+        return Err("User is not in an experience".to_string());
+    }
+
+    let uri = match (presence.place_id, presence.game_id) {
+        (Some(place_id), Some(game_id)) => {
+            let uri = format!(
+                "roblox://experiences/start?placeId={}&gameInstanceId={}",
+                place_id, game_id
+            );
+
+            recent_servers.record(game_id);
+            uri
+        }
+        _ => format!("roblox://experiences/start?userId={}", user_id),
+    };
+
+    api::shell::open(&app.shell_scope(), uri, None).map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn ranked_servers(
+    state: State<'_, RobloxApiState>,
+    recent_servers: State<'_, RecentServersState>,
+    place_id: u64,
+    options: Option<ServerRankingOptions>,
+    exclude_recent: Option<bool>,
+) -> Result<Vec<RankedServer>, String> {
+    let mut options = options.unwrap_or_default();
+
+    if exclude_recent.unwrap_or(false) {
+        options.exclude_server_ids.extend(recent_servers.ids());
+    }
+
+    let client = state.0.read().await;
+
+    client
+        .ranked_game_servers(place_id, &options)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Joins the best ranked server of the place, returning it.
+#[tauri::command(async)]
+pub async fn open_best_server<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    recent_servers: State<'_, RecentServersState>,
+    place_id: u64,
+    options: Option<ServerRankingOptions>,
+    exclude_recent: Option<bool>,
+) -> Result<RankedServer, String> {
+    let best_server = ranked_servers(
+        state,
+        recent_servers.clone(),
+        place_id,
+        options,
+        exclude_recent,
+    )
+    .await?
+    .into_iter()
+    .next()
+    .ok_or("No joinable servers found")?;
+
+    recent_servers.record(best_server.server.id.clone());
+
+    api::shell::open(
+        &app.shell_scope(),
+        format!(
+            "roblox://experiences/start?placeId={}&gameInstanceId={}",
+            place_id, best_server.server.id
+        ),
+        None,
+    )
+    .map_err(|err| err.to_string())?;
+
+    Ok(best_server)
+}
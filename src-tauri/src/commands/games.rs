@@ -1,6 +1,10 @@
 use tauri::State;
 
-use crate::client::games::{GameDetail, GameMedia, GameServer, PlaceDetails, ServerType};
+use crate::client::games::{
+    GameDetail, GameMedia, GameServer, PlaceDetails, ServerPlayer, ServerType, SortOrder,
+};
+use crate::client::Limit;
+use crate::services::player_finder::DEFAULT_MAX_PAGES;
 use crate::types::RobloxApiState;
 
 #[tauri::command(async)]
@@ -42,17 +46,63 @@ pub async fn game_details(
         .map_err(|err| err.to_string())
 }
 
+/// With `load_all`, every page (up to `max_pages`) is loaded and no cursor is returned.
+/// `max_pages` defaults to, and is capped at, [`DEFAULT_MAX_PAGES`].
+#[allow(clippy::too_many_arguments)]
 #[tauri::command(async)]
 pub async fn game_servers(
     state: State<'_, RobloxApiState>,
     place_id: u64,
     servers_type: ServerType,
+    sort_order: Option<SortOrder>,
+    exclude_full_games: Option<bool>,
+    limit: Option<Limit>,
     cursor: Option<String>,
+    load_all: Option<bool>,
+    max_pages: Option<usize>,
 ) -> Result<(Vec<GameServer>, Option<String>), String> {
     let client = state.0.read().await;
 
+    if load_all.unwrap_or(false) {
+        return client
+            .all_game_servers(
+                place_id,
+                Some(servers_type),
+                sort_order,
+                exclude_full_games,
+                Some(
+                    max_pages
+                        .unwrap_or(DEFAULT_MAX_PAGES)
+                        .clamp(1, DEFAULT_MAX_PAGES),
+                ),
+            )
+            .await
+            .map(|servers| (servers, None))
+            .map_err(|err| err.to_string());
+    }
+
+    client
+        .game_servers(
+            place_id,
+            Some(servers_type),
+            sort_order,
+            exclude_full_games,
+            limit,
+            cursor,
+        )
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn server_players(
+    state: State<'_, RobloxApiState>,
+    player_tokens: Vec<String>,
+) -> Result<Vec<ServerPlayer>, String> {
+    let client = state.0.read().await;
+
     client
-        .game_servers(place_id, Some(servers_type), None, None, cursor)
+        .server_players(player_tokens)
         .await
         .map_err(|err| err.to_string())
 }
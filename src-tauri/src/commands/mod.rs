@@ -1,11 +1,25 @@
+mod friend_notes;
+mod friend_snapshots;
+mod friend_watcher;
 mod game_launcher;
 mod games;
+mod player_finder;
+mod presence_heartbeat;
+mod presence_history;
+mod private_servers;
 mod search;
 mod thumbnails;
 mod users;
 
+pub use friend_notes::*;
+pub use friend_snapshots::*;
+pub use friend_watcher::*;
 pub use game_launcher::*;
 pub use games::*;
+pub use player_finder::*;
+pub use presence_heartbeat::*;
+pub use presence_history::*;
+pub use private_servers::*;
 pub use search::*;
 pub use thumbnails::*;
 pub use users::*;
@@ -0,0 +1,28 @@
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{
+    services::player_finder::{self, FoundServer, DEFAULT_MAX_PAGES},
+    types::PlayerFinderState,
+};
+
+/// Progress is reported through `find-player-progress` events.
+#[tauri::command(async)]
+pub async fn find_player<R: Runtime>(
+    app: AppHandle<R>,
+    user_id: u64,
+    place_id: Option<u64>,
+    max_pages: Option<usize>,
+) -> Result<Option<FoundServer>, String> {
+    player_finder::find(
+        &app,
+        user_id,
+        place_id,
+        max_pages.unwrap_or(DEFAULT_MAX_PAGES),
+    )
+    .await
+}
+
+#[tauri::command]
+pub fn cancel_find_player(state: State<'_, PlayerFinderState>, user_id: u64) {
+    state.cancel(user_id);
+}
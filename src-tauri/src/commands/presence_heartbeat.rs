@@ -0,0 +1,40 @@
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{
+    services::presence_heartbeat::MIN_INTERVAL_SECS,
+    storage::preferences,
+    types::{PresenceHeartbeatSettings, PresenceHeartbeatState, PresenceHeartbeatStatus},
+};
+
+#[tauri::command(async)]
+pub async fn presence_heartbeat_settings(
+    state: State<'_, PresenceHeartbeatState>,
+) -> Result<PresenceHeartbeatSettings, ()> {
+    Ok(state.settings.read().await.clone())
+}
+
+/// New settings are saved and applied right away, without waiting for the next tick.
+#[tauri::command(async)]
+pub async fn set_presence_heartbeat_settings<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, PresenceHeartbeatState>,
+    settings: PresenceHeartbeatSettings,
+) -> Result<(), String> {
+    let settings = PresenceHeartbeatSettings {
+        interval_secs: settings.interval_secs.max(MIN_INTERVAL_SECS),
+        ..settings
+    };
+
+    preferences::set_presence_heartbeat(&app, &settings)?;
+    *state.settings.write().await = settings;
+    state.wake.notify_one();
+
+    Ok(())
+}
+
+#[tauri::command(async)]
+pub async fn presence_heartbeat_status(
+    state: State<'_, PresenceHeartbeatState>,
+) -> Result<PresenceHeartbeatStatus, ()> {
+    Ok(state.status.read().await.clone())
+}
@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use tauri::{AppHandle, Runtime, State};
+
+use crate::{
+    services,
+    storage::presence_history::{
+        self, DailyActivity, PresenceHistory, PresenceSession, UniversePlaytime,
+    },
+    types::RobloxApiState,
+};
+
+/// Loads the presence history of the logged in account.
+async fn history<R: Runtime>(
+    app: &AppHandle<R>,
+    state: &State<'_, RobloxApiState>,
+) -> Result<PresenceHistory, String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    Ok(presence_history::load(app, account_id))
+}
+
+#[tauri::command(async)]
+pub async fn presence_timeline<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    days: Option<u64>,
+) -> Result<Vec<PresenceSession>, String> {
+    let since = presence_history::days_ago(services::unix_now(), days.unwrap_or(7));
+
+    Ok(history(&app, &state).await?.timeline(user_id, since))
+}
+
+#[tauri::command(async)]
+pub async fn last_seen<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_ids: Vec<u64>,
+) -> Result<HashMap<u64, u64>, String> {
+    Ok(history(&app, &state).await?.last_seen(&user_ids))
+}
+
+#[tauri::command(async)]
+pub async fn most_played_with_friends<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    days: Option<u64>,
+) -> Result<Vec<UniversePlaytime>, String> {
+    let since = presence_history::days_ago(services::unix_now(), days.unwrap_or(7));
+
+    Ok(history(&app, &state).await?.most_played(since))
+}
+
+#[tauri::command(async)]
+pub async fn user_activity<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    days: Option<u64>,
+) -> Result<Vec<DailyActivity>, String> {
+    let since = presence_history::days_ago(services::unix_now(), days.unwrap_or(7));
+
+    Ok(history(&app, &state).await?.daily_activity(user_id, since))
+}
+
+#[tauri::command(async)]
+pub async fn watched_users<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+) -> Result<HashSet<u64>, String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    Ok(presence_history::watched_users(&app, account_id))
+}
+
+#[tauri::command(async)]
+pub async fn set_user_watched<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    watched: bool,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    presence_history::set_watched(&app, account_id, user_id, watched)
+}
@@ -0,0 +1,97 @@
+use tauri::State;
+
+use crate::{
+    client::{
+        games::{
+            PrivateServer, PrivateServerDetails, PrivateServerOptions, PrivateServerPermissions,
+            PrivateServerPermissionsUpdate, PrivateServerUpdate,
+        },
+        Limit,
+    },
+    types::RobloxApiState,
+};
+
+#[tauri::command(async)]
+pub async fn private_servers(
+    state: State<'_, RobloxApiState>,
+    place_id: u64,
+    limit: Option<Limit>,
+    cursor: Option<String>,
+) -> Result<(Vec<PrivateServer>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .private_servers(place_id, limit, cursor)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn private_server_options(
+    state: State<'_, RobloxApiState>,
+    universe_id: u64,
+) -> Result<PrivateServerOptions, String> {
+    let client = state.0.read().await;
+
+    client
+        .private_server_options(universe_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn create_private_server(
+    state: State<'_, RobloxApiState>,
+    universe_id: u64,
+    name: String,
+    expected_price: u64,
+    purchase_confirmed: bool,
+) -> Result<PrivateServerDetails, String> {
+    let client = state.0.read().await;
+
+    client
+        .create_private_server(universe_id, name, expected_price, purchase_confirmed)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn private_server(
+    state: State<'_, RobloxApiState>,
+    vip_server_id: u64,
+) -> Result<PrivateServerDetails, String> {
+    let client = state.0.read().await;
+
+    client
+        .private_server(vip_server_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn update_private_server(
+    state: State<'_, RobloxApiState>,
+    vip_server_id: u64,
+    update: PrivateServerUpdate,
+) -> Result<PrivateServerDetails, String> {
+    let client = state.0.read().await;
+
+    client
+        .update_private_server(vip_server_id, update)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn set_private_server_permissions(
+    state: State<'_, RobloxApiState>,
+    vip_server_id: u64,
+    update: PrivateServerPermissionsUpdate,
+) -> Result<PrivateServerPermissions, String> {
+    let client = state.0.read().await;
+
+    client
+        .set_private_server_permissions(vip_server_id, update)
+        .await
+        .map_err(|err| err.to_string())
+}
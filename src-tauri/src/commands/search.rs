@@ -1,6 +1,9 @@
 use tauri::State;
 
-use crate::{client::search::SearchContent, types::RobloxApiState};
+use crate::{
+    client::{search::SearchContent, users::UserSearchResult, Limit},
+    types::RobloxApiState,
+};
 
 #[tauri::command(async)]
 pub async fn omni_search(
@@ -14,3 +17,24 @@ pub async fn omni_search(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command(async)]
+pub async fn user_search(
+    state: State<'_, RobloxApiState>,
+    keyword: String,
+    limit: Option<Limit>,
+    cursor: Option<String>,
+    exclude_blocked: Option<bool>,
+) -> Result<(Vec<UserSearchResult>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .user_search(
+            keyword,
+            limit.unwrap_or_default(),
+            cursor,
+            exclude_blocked.unwrap_or(false),
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -32,3 +32,33 @@ pub async fn token_thumbnail_url_bulk(
         .await
         .map_err(|err| err.to_string())
 }
+
+#[tauri::command(async)]
+pub async fn completed_thumbnail_url_bulk(
+    state: State<'_, RobloxApiState>,
+    ids: Vec<u64>,
+    thumbnail_size: ThumbnailSize,
+    thumbnail_type: ThumbnailType,
+) -> Result<Vec<Option<String>>, String> {
+    let client = state.0.read().await;
+
+    client
+        .completed_thumbnail_url_bulk(ids, thumbnail_size, thumbnail_type)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn completed_token_thumbnail_url_bulk(
+    state: State<'_, RobloxApiState>,
+    tokens: Vec<String>,
+    thumbnail_size: ThumbnailSize,
+    thumbnail_type: ThumbnailType,
+) -> Result<Vec<Option<String>>, String> {
+    let client = state.0.read().await;
+
+    client
+        .completed_token_thumbnail_url_bulk(tokens, thumbnail_size, thumbnail_type)
+        .await
+        .map_err(|err| err.to_string())
+}
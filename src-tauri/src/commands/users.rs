@@ -1,11 +1,18 @@
-use tauri::State;
+use std::collections::HashMap;
 
-use crate::client::friends::{FriendStatus, FriendUserInformation};
-use crate::client::users::UserDetails;
-use crate::types::UserProfileStats;
+use tauri::{AppHandle, Runtime, State};
+
+use crate::client::friends::{
+    FriendRecommendation, FriendRequest, FriendRequestFilter, FriendStatus, FriendUserInformation,
+    FriendsActivity, FriendshipOriginSource, MutualFriend, OnlineFriend,
+};
+use crate::client::games::SortOrder;
+use crate::client::users::{PromotionChannels, UserDetails, UserProfile, UserProfileStats};
+use crate::client::User;
+use crate::storage::friend_notes;
 use crate::{
-    client::RobloxError,
-    types::{ClientInfo, RobloxApiState},
+    client::{Limit, RobloxError},
+    types::{BulkActionResult, ClientInfo, RobloxApiState},
 };
 
 #[tauri::command]
@@ -37,6 +44,21 @@ pub async fn get_user(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command(async)]
+pub async fn username_history(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    limit: Option<Limit>,
+    cursor: Option<String>,
+) -> Result<(Vec<String>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .username_history(user_id, limit.unwrap_or_default(), cursor)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command(async)]
 pub async fn get_user_stats(
     state: State<'_, RobloxApiState>,
@@ -44,20 +66,23 @@ pub async fn get_user_stats(
 ) -> Result<UserProfileStats, String> {
     let client = state.0.read().await;
 
-    Ok(UserProfileStats {
-        friends: client
-            .friends_count(user_id)
-            .await
-            .map_err(|e| e.to_string())?,
-        followers: client
-            .followers_count(user_id)
-            .await
-            .map_err(|e| e.to_string())?,
-        followings: client
-            .followings_count(user_id)
-            .await
-            .map_err(|e| e.to_string())?,
-    })
+    client
+        .user_profile_stats(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn get_user_profile(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+) -> Result<UserProfile, String> {
+    let client = state.0.read().await;
+
+    client
+        .user_profile(user_id)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command(async)]
@@ -74,36 +99,187 @@ pub async fn friend_status(
 }
 
 #[tauri::command(async)]
-pub async fn friends_list(
+pub async fn friend_statuses(
+    state: State<'_, RobloxApiState>,
+    user_ids: Vec<u64>,
+) -> Result<HashMap<u64, FriendStatus>, String> {
+    let client = state.0.read().await;
+
+    client
+        .friend_statuses(user_ids)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn friends_list<R: Runtime>(
+    app: AppHandle<R>,
     state: State<'_, RobloxApiState>,
 ) -> Result<Vec<FriendUserInformation>, String> {
     let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
+
+    let mut friends = client
+        .friends_list(account_id)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    friend_notes::merge(&mut friends, &friend_notes::load(&app, account_id));
+    friend_notes::sort_pinned_first(&mut friends);
+
+    Ok(friends)
+}
+
+#[tauri::command(async)]
+pub async fn online_friends(state: State<'_, RobloxApiState>) -> Result<Vec<OnlineFriend>, String> {
+    let client = state.0.read().await;
 
     client
-        .friends_list(client.user_id().await.map_err(|err| err.to_string())?)
+        .online_friends(client.user_id().await.map_err(|err| err.to_string())?)
         .await
         .map_err(|err| err.to_string())
 }
 
 #[tauri::command(async)]
-pub async fn users_friends_list(
+pub async fn users_friends_list<R: Runtime>(
+    app: AppHandle<R>,
     state: State<'_, RobloxApiState>,
     user_id: u64,
 ) -> Result<Vec<FriendUserInformation>, String> {
     let client = state.0.read().await;
 
-    client
+    let mut friends = client
         .friends_list(user_id)
         .await
+        .map_err(|err| err.to_string())?;
+
+    if let Ok(account_id) = client.user_id().await {
+        friend_notes::merge(&mut friends, &friend_notes::load(&app, account_id));
+    }
+
+    Ok(friends)
+}
+
+#[tauri::command(async)]
+pub async fn mutual_friends(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+) -> Result<Vec<MutualFriend>, String> {
+    let client = state.0.read().await;
+
+    client
+        .mutual_friends(user_id)
+        .await
         .map_err(|err| err.to_string())
 }
 
 #[tauri::command(async)]
-pub async fn friend(state: State<'_, RobloxApiState>, user_id: u64) -> Result<(), String> {
+pub async fn friends_activity(
+    state: State<'_, RobloxApiState>,
+) -> Result<Vec<FriendsActivity>, String> {
     let client = state.0.read().await;
+    let account_id = client.user_id().await.map_err(|err| err.to_string())?;
 
     client
-        .send_friend_request(user_id)
+        .friends_activity(account_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn friend_recommendations(
+    state: State<'_, RobloxApiState>,
+) -> Result<Vec<FriendRecommendation>, String> {
+    let client = state.0.read().await;
+
+    client
+        .friend_recommendations()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn dismiss_friend_recommendation(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .dismiss_friend_recommendation(user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn followers(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    limit: Option<Limit>,
+    sort_order: Option<SortOrder>,
+    cursor: Option<String>,
+) -> Result<(Vec<FriendUserInformation>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .followers(
+            user_id,
+            limit.unwrap_or_default(),
+            sort_order.unwrap_or_default(),
+            cursor,
+        )
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn followings(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    limit: Option<Limit>,
+    sort_order: Option<SortOrder>,
+    cursor: Option<String>,
+) -> Result<(Vec<FriendUserInformation>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .followings(
+            user_id,
+            limit.unwrap_or_default(),
+            sort_order.unwrap_or_default(),
+            cursor,
+        )
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn follow(state: State<'_, RobloxApiState>, user_id: u64) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client.follow(user_id).await.map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn unfollow(state: State<'_, RobloxApiState>, user_id: u64) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .unfollow(user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn friend(
+    state: State<'_, RobloxApiState>,
+    user_id: u64,
+    origin_source: Option<FriendshipOriginSource>,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .send_friend_request(user_id, origin_source.unwrap_or_default())
         .await
         .map_err(|err| err.to_string())
 }
@@ -118,6 +294,104 @@ pub async fn unfriend(state: State<'_, RobloxApiState>, user_id: u64) -> Result<
         .map_err(|err| err.to_string())
 }
 
+#[tauri::command(async)]
+pub async fn friend_requests(
+    state: State<'_, RobloxApiState>,
+    limit: Option<Limit>,
+    cursor: Option<String>,
+    exclude_blocked: Option<bool>,
+) -> Result<(Vec<FriendRequest>, Option<String>), String> {
+    let client = state.0.read().await;
+
+    client
+        .friend_requests(
+            limit.unwrap_or_default(),
+            cursor,
+            exclude_blocked.unwrap_or(false),
+        )
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn all_friend_requests(
+    state: State<'_, RobloxApiState>,
+    exclude_blocked: Option<bool>,
+) -> Result<Vec<FriendRequest>, String> {
+    let client = state.0.read().await;
+
+    client
+        .all_friend_requests(exclude_blocked.unwrap_or(false))
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn pending_friend_requests(state: State<'_, RobloxApiState>) -> Result<u64, String> {
+    let client = state.0.read().await;
+
+    client
+        .pending_friend_requests()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn accept_friend_requests(
+    state: State<'_, RobloxApiState>,
+    filter: FriendRequestFilter,
+) -> Result<Vec<BulkActionResult>, String> {
+    let client = state.0.read().await;
+
+    let (results, skipped_user_ids) = client
+        .accept_friend_requests(filter)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(results
+        .into_iter()
+        .map(|(user_id, result)| BulkActionResult {
+            user_id,
+            success: result.is_ok(),
+            skipped: false,
+            error: result.err().map(|err| err.to_string()),
+        })
+        .chain(
+            skipped_user_ids
+                .into_iter()
+                .map(|user_id| BulkActionResult {
+                    user_id,
+                    success: false,
+                    skipped: true,
+                    error: Some("User is blocked".to_string()),
+                }),
+        )
+        .collect())
+}
+
+#[tauri::command(async)]
+pub async fn decline_friend_requests(
+    state: State<'_, RobloxApiState>,
+    filter: FriendRequestFilter,
+) -> Result<Vec<BulkActionResult>, String> {
+    let client = state.0.read().await;
+
+    let results = client
+        .decline_friend_requests(filter)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    Ok(results
+        .into_iter()
+        .map(|(user_id, result)| BulkActionResult {
+            user_id,
+            success: result.is_ok(),
+            skipped: false,
+            error: result.err().map(|err| err.to_string()),
+        })
+        .collect())
+}
+
 #[tauri::command(async)]
 pub async fn accept_friend_request(
     state: State<'_, RobloxApiState>,
@@ -143,3 +417,98 @@ pub async fn decline_friend_request(
         .await
         .map_err(|err| err.to_string())
 }
+
+#[tauri::command(async)]
+pub async fn set_description(
+    state: State<'_, RobloxApiState>,
+    description: String,
+) -> Result<String, String> {
+    let client = state.0.read().await;
+
+    client
+        .set_description(description)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn validate_display_name(
+    state: State<'_, RobloxApiState>,
+    display_name: String,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .validate_display_name(display_name)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn set_display_name(
+    state: State<'_, RobloxApiState>,
+    display_name: String,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .set_display_name(display_name)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn promotion_channels(
+    state: State<'_, RobloxApiState>,
+) -> Result<PromotionChannels, String> {
+    let client = state.0.read().await;
+
+    client
+        .promotion_channels()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn set_promotion_channels(
+    state: State<'_, RobloxApiState>,
+    promotion_channels: PromotionChannels,
+) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client
+        .set_promotion_channels(promotion_channels)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn blocked_users(state: State<'_, RobloxApiState>) -> Result<Vec<User>, String> {
+    let client = state.0.read().await;
+
+    client.blocked_users().await.map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn is_blocked(state: State<'_, RobloxApiState>, user_id: u64) -> Result<bool, String> {
+    let client = state.0.read().await;
+
+    client
+        .is_blocked(user_id)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn block(state: State<'_, RobloxApiState>, user_id: u64) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client.block(user_id).await.map_err(|err| err.to_string())
+}
+
+#[tauri::command(async)]
+pub async fn unblock(state: State<'_, RobloxApiState>, user_id: u64) -> Result<(), String> {
+    let client = state.0.read().await;
+
+    client.unblock(user_id).await.map_err(|err| err.to_string())
+}
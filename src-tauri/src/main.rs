@@ -4,6 +4,8 @@
 mod client;
 mod commands;
 mod roblox_api;
+mod services;
+mod storage;
 mod types;
 
 fn main() {
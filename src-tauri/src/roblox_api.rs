@@ -6,7 +6,11 @@ use tauri::{
 
 use crate::{
     client::{discovery::RecommendationsTopic, presence::UserPresence},
-    types::RobloxApiState,
+    services, storage,
+    types::{
+        FriendWatcherState, PlayerFinderState, PresenceHeartbeatState, RecentServersState,
+        RobloxApiState,
+    },
 };
 
 use super::commands;
@@ -39,18 +43,6 @@ async fn is_authed(state: State<'_, RobloxApiState>) -> Result<bool, ()> {
     Ok(client.cookie_string().await.is_ok())
 }
 
-#[tauri::command(async)]
-async fn presence(state: State<'_, RobloxApiState>) -> Result<(), String> {
-    let client = state.0.read().await;
-
-    client
-        .register_presence()
-        .await
-        .map_err(|err| err.to_string())?;
-
-    Ok(())
-}
-
 #[tauri::command(async)]
 async fn get_presences(
     state: State<'_, RobloxApiState>,
@@ -81,36 +73,116 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
         .invoke_handler(tauri::generate_handler![
             auth,
             is_authed,
-            presence,
             recommendations,
             get_presences,
             // users.rs
             commands::get_me,
             commands::get_user,
+            commands::username_history,
             commands::get_user_stats,
+            commands::get_user_profile,
             commands::friend_status,
+            commands::friend_statuses,
             commands::friends_list,
+            commands::online_friends,
+            commands::friends_activity,
             commands::users_friends_list,
+            commands::mutual_friends,
+            commands::friend_recommendations,
+            commands::dismiss_friend_recommendation,
+            commands::followers,
+            commands::followings,
+            commands::follow,
+            commands::unfollow,
             commands::friend,
             commands::unfriend,
+            commands::friend_requests,
+            commands::all_friend_requests,
+            commands::pending_friend_requests,
+            commands::accept_friend_requests,
+            commands::decline_friend_requests,
             commands::accept_friend_request,
             commands::decline_friend_request,
+            commands::set_description,
+            commands::validate_display_name,
+            commands::set_display_name,
+            commands::promotion_channels,
+            commands::set_promotion_channels,
+            commands::blocked_users,
+            commands::is_blocked,
+            commands::block,
+            commands::unblock,
             // games.rs
             commands::game_media,
             commands::game_details,
             commands::game_servers,
             commands::place_details,
+            commands::server_players,
             // thumbnails.rs
             commands::thumbnail_url_bulk,
             commands::token_thumbnail_url_bulk,
+            commands::completed_thumbnail_url_bulk,
+            commands::completed_token_thumbnail_url_bulk,
             // search.rs
             commands::omni_search,
+            commands::user_search,
+            // friend_notes.rs
+            commands::friend_notes,
+            commands::set_friend_note,
+            commands::set_friend_pinned,
+            // friend_snapshots.rs
+            commands::export_friends,
+            commands::friends_snapshots,
+            commands::take_friends_snapshot,
+            commands::friends_snapshot_diff,
+            // friend_watcher.rs
+            commands::friend_watcher_interval,
+            commands::set_friend_watcher_interval,
+            // presence_heartbeat.rs
+            commands::presence_heartbeat_settings,
+            commands::set_presence_heartbeat_settings,
+            commands::presence_heartbeat_status,
+            // presence_history.rs
+            commands::presence_timeline,
+            commands::last_seen,
+            commands::most_played_with_friends,
+            commands::user_activity,
+            commands::watched_users,
+            commands::set_user_watched,
             // game_launcher.rs
             commands::open_place,
             commands::open_server,
+            commands::open_private_server,
+            commands::join_user,
+            commands::ranked_servers,
+            commands::open_best_server,
+            // player_finder.rs
+            commands::find_player,
+            commands::cancel_find_player,
+            // private_servers.rs
+            commands::private_servers,
+            commands::private_server_options,
+            commands::create_private_server,
+            commands::private_server,
+            commands::update_private_server,
+            commands::set_private_server_permissions,
         ])
         .setup(|app_handle| {
             app_handle.manage(RobloxApiState::default());
+            app_handle.manage(
+                storage::preferences::friend_watcher_interval(app_handle)
+                    .map(FriendWatcherState::new)
+                    .unwrap_or_default(),
+            );
+            app_handle.manage(PresenceHeartbeatState::new(
+                storage::preferences::presence_heartbeat(app_handle),
+            ));
+            app_handle.manage(RecentServersState::default());
+            app_handle.manage(PlayerFinderState::default());
+
+            services::friend_watcher::spawn(app_handle.clone());
+            services::friend_snapshots::spawn(app_handle.clone());
+            services::presence_heartbeat::spawn(app_handle.clone());
             Ok(())
         })
         .build()
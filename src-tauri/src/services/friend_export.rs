@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{friends::FriendUserInformation, RobloxApi, RobloxError};
+
+/// Which list of a user gets exported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExportList {
+    Friends,
+    Followers,
+    Followings,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+const CSV_HEADER: &str = "user_id,username,display_name,created,is_terminated,has_verified_badge";
+
+/// Fetches the whole list, going through every page for followers and followings.
+pub async fn fetch(
+    client: &RobloxApi,
+    user_id: u64,
+    list: ExportList,
+) -> Result<Vec<FriendUserInformation>, RobloxError> {
+    match list {
+        ExportList::Friends => client.friends_list(user_id).await,
+        ExportList::Followers => client.all_followers(user_id).await,
+        ExportList::Followings => client.all_followings(user_id).await,
+    }
+}
+
+/// Serializes users into the requested format.
+pub fn format(users: &[FriendUserInformation], format: ExportFormat) -> Result<String, String> {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(users).map_err(|err| err.to_string()),
+        ExportFormat::Csv => {
+            let mut csv = String::from(CSV_HEADER);
+
+            for user in users {
+                csv.push('\n');
+                csv.push_str(
+                    &[
+                        user.user_id.to_string(),
+                        escape_csv(&user.username),
+                        escape_csv(&user.display_name),
+                        escape_csv(&user.created),
+                        user.is_terminated.to_string(),
+                        user.has_verified_badge.to_string(),
+                    ]
+                    .join(","),
+                );
+            }
+
+            Ok(csv)
+        }
+    }
+}
+
+/// Quotes a field if it contains characters that would break the row.
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_csv;
+
+    #[test]
+    fn plain_fields_are_unchanged() {
+        assert_eq!(escape_csv("builderman"), "builderman");
+        assert_eq!(escape_csv(""), "");
+    }
+
+    #[test]
+    fn fields_with_separators_are_quoted() {
+        assert_eq!(escape_csv("a,b"), "\"a,b\"");
+        assert_eq!(escape_csv("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(escape_csv("carriage\rreturn"), "\"carriage\rreturn\"");
+    }
+
+    #[test]
+    fn quotes_are_doubled() {
+        assert_eq!(escape_csv("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
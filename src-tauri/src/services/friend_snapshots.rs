@@ -0,0 +1,137 @@
+use std::{collections::HashSet, time::Duration};
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::{
+    client::{RobloxApi, RobloxError},
+    storage::friend_snapshots::{self, FriendsDiff, FriendsSnapshot, SnapshotUser},
+    types::RobloxApiState,
+};
+
+/// How often the service checks whether a new snapshot is due.
+const CHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Minimum age of the latest snapshot before a new one is taken automatically.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Name of the Tauri event emitted when an automatic snapshot found changes.
+const DIFF_EVENT: &str = "friends-snapshot-diff";
+
+/// Spawns the service, which takes a snapshot of the logged in user's friends once per
+/// [`SNAPSHOT_INTERVAL`] and emits [`DIFF_EVENT`] when something changed since the last one.
+pub fn spawn<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let account_id = {
+                let state = app.state::<RobloxApiState>();
+                let client = state.0.read().await;
+
+                client.user_id().await
+            };
+
+            let Ok(account_id) = account_id else {
+                continue;
+            };
+
+            let is_recent = friend_snapshots::latest(&app, account_id).is_some_and(|snapshot| {
+                super::unix_now().saturating_sub(snapshot.taken_at) < SNAPSHOT_INTERVAL.as_secs()
+            });
+
+            if is_recent {
+                continue;
+            }
+
+            if let Ok(Some(diff)) = snapshot_now(&app, account_id).await {
+                if !diff.is_empty() {
+                    let _ = app.emit_all(DIFF_EVENT, diff);
+                }
+            }
+        }
+    });
+}
+
+/// Takes and stores a new snapshot. Returns changes since the previous snapshot,
+/// or `None` if this is the first one.
+///
+/// The client is locked per request, so logging in or out isn't blocked for the whole snapshot.
+/// Callers must not hold the client lock themselves.
+pub async fn snapshot_now<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+) -> Result<Option<FriendsDiff>, String> {
+    let previous = friend_snapshots::latest(app, account_id);
+
+    let current = {
+        let state = app.state::<RobloxApiState>();
+        let client = state.0.read().await;
+
+        take(&client, account_id)
+            .await
+            .map_err(|err| err.to_string())?
+    };
+
+    let diff = match &previous {
+        Some(previous) => {
+            let state = app.state::<RobloxApiState>();
+            let client = state.0.read().await;
+
+            Some(compare(&client, previous, &current).await)
+        }
+        None => None,
+    };
+
+    friend_snapshots::push(app, account_id, current)?;
+
+    Ok(diff)
+}
+
+/// Fetches the current friends list without storing it.
+pub async fn take(client: &RobloxApi, account_id: u64) -> Result<FriendsSnapshot, RobloxError> {
+    let friends = client.friends_list(account_id).await?;
+
+    Ok(FriendsSnapshot {
+        taken_at: super::unix_now(),
+        friends: friends.into_iter().map(Into::into).collect(),
+    })
+}
+
+/// Diffs two snapshots, looking up users who left the friends list to tell
+/// terminated accounts apart from unfriends.
+pub async fn compare(
+    client: &RobloxApi,
+    previous: &FriendsSnapshot,
+    current: &FriendsSnapshot,
+) -> FriendsDiff {
+    let mut diff = friend_snapshots::diff(previous, current);
+
+    if diff.unfriended.is_empty() {
+        return diff;
+    }
+
+    let user_ids = diff.unfriended.iter().map(|user| user.user_id).collect();
+
+    // Banned users are left out of the response, so whoever is missing got terminated.
+    // Lookup failures are reported as unfriends rather than dropped.
+    let Ok(active_users) = client.users_details(user_ids, true).await else {
+        return diff;
+    };
+
+    let active_ids = active_users
+        .into_iter()
+        .map(|user| user.id)
+        .collect::<HashSet<_>>();
+
+    let (unfriended, terminated) = std::mem::take(&mut diff.unfriended)
+        .into_iter()
+        .partition::<Vec<_>, _>(|user| active_ids.contains(&user.user_id));
+
+    diff.unfriended = unfriended;
+    diff.terminated
+        .extend(terminated.into_iter().map(|user| SnapshotUser {
+            is_terminated: true,
+            ..user
+        }));
+    diff
+}
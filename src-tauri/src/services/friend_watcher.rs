@@ -0,0 +1,295 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::{
+    client::{
+        presence::{PresenceType, UserPresence},
+        RobloxError,
+    },
+    storage::presence_history,
+    types::{FriendWatcherState, RobloxApiState},
+};
+
+/// The maximum amount of user ids sent in a single presence request.
+const PRESENCE_CHUNK_SIZE: usize = 50;
+
+/// Kind of change in a friend's presence. Each kind is emitted as its own event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FriendActivity {
+    CameOnline,
+    JoinedExperience,
+    LeftGame,
+    WentOffline,
+    StartedStudio,
+}
+
+impl FriendActivity {
+    /// Name of the Tauri event emitted for this activity.
+    pub fn event_name(self) -> &'static str {
+        match self {
+            Self::CameOnline => "friend-came-online",
+            Self::JoinedExperience => "friend-joined-experience",
+            Self::LeftGame => "friend-left-game",
+            Self::WentOffline => "friend-went-offline",
+            Self::StartedStudio => "friend-started-studio",
+        }
+    }
+}
+
+/// Payload of friend activity events.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FriendActivityEvent {
+    pub user_id: u64,
+    pub activity: FriendActivity,
+    pub previous: UserPresence,
+    pub current: UserPresence,
+}
+
+/// Spawns the watcher, which polls presences of the logged in user's friends and watched
+/// users, emits an event for every observed change and records them to the presence history.
+pub fn spawn<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let mut presences = HashMap::new();
+
+        loop {
+            let interval = *app.state::<FriendWatcherState>().interval.read().await;
+            tokio::time::sleep(interval).await;
+
+            let account_id = {
+                let state = app.state::<RobloxApiState>();
+                let client = state.0.read().await;
+
+                client.user_id().await
+            };
+
+            let Ok(account_id) = account_id else {
+                continue;
+            };
+
+            let watched_user_ids = presence_history::watched_users(&app, account_id);
+
+            let Ok(new_presences) = friends_presences(&app, account_id, watched_user_ids).await
+            else {
+                // Polling failed (e.g. rate limited or logged out), keep the last known
+                // presences and retry on the next tick.
+                continue;
+            };
+
+            for event in diff_presences(&presences, &new_presences) {
+                let _ = app.emit_all(event.activity.event_name(), event);
+            }
+
+            let mut history = presence_history::load(&app, account_id);
+            history.record(&new_presences, super::unix_now());
+            let _ = presence_history::save(&app, account_id, &history);
+
+            presences = new_presences
+                .into_iter()
+                .map(|presence| (presence.user_id, presence))
+                .collect();
+        }
+    });
+}
+
+/// Fetches presences of every friend of the account and of the additionally watched users.
+///
+/// The client is locked per request, so logging in or out isn't blocked for the whole poll.
+async fn friends_presences<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+    watched_user_ids: HashSet<u64>,
+) -> Result<Vec<UserPresence>, RobloxError> {
+    let friends = {
+        let state = app.state::<RobloxApiState>();
+        let client = state.0.read().await;
+
+        client.friends_list(account_id).await?
+    };
+    let friend_ids = friends
+        .into_iter()
+        .map(|friend| friend.user_id)
+        .chain(watched_user_ids)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut presences = Vec::new();
+
+    for chunk in friend_ids.chunks(PRESENCE_CHUNK_SIZE) {
+        let state = app.state::<RobloxApiState>();
+        let client = state.0.read().await;
+
+        presences.extend(client.get_presence(chunk.to_vec()).await?);
+    }
+
+    Ok(presences)
+}
+
+/// Compares new presences against the previous ones. Users without a previous presence
+/// (e.g. on the first poll or new friends) don't produce any events.
+fn diff_presences(
+    previous_presences: &HashMap<u64, UserPresence>,
+    new_presences: &[UserPresence],
+) -> Vec<FriendActivityEvent> {
+    let mut events = Vec::new();
+
+    for current in new_presences {
+        let Some(previous) = previous_presences.get(&current.user_id) else {
+            continue;
+        };
+
+        let was_online = is_online(&previous.presence_type);
+        let is_now_online = is_online(&current.presence_type);
+
+        let was_in_game = previous.presence_type == PresenceType::InGame;
+        let is_now_in_game = current.presence_type == PresenceType::InGame;
+        let changed_game =
+            previous.universe_id != current.universe_id || previous.game_id != current.game_id;
+
+        let mut activities = Vec::new();
+
+        if !was_online && is_now_online {
+            activities.push(FriendActivity::CameOnline);
+        }
+
+        if was_in_game && (!is_now_in_game || changed_game) {
+            activities.push(FriendActivity::LeftGame);
+        }
+
+        if is_now_in_game && (!was_in_game || changed_game) {
+            activities.push(FriendActivity::JoinedExperience);
+        }
+
+        if previous.presence_type != PresenceType::InStudio
+            && current.presence_type == PresenceType::InStudio
+        {
+            activities.push(FriendActivity::StartedStudio);
+        }
+
+        if was_online && !is_now_online {
+            activities.push(FriendActivity::WentOffline);
+        }
+
+        events.extend(activities.into_iter().map(|activity| FriendActivityEvent {
+            user_id: current.user_id,
+            activity,
+            previous: previous.clone(),
+            current: current.clone(),
+        }));
+    }
+
+    events
+}
+
+/// Invisible users are reported as offline to everyone else.
+fn is_online(presence_type: &PresenceType) -> bool {
+    !matches!(
+        presence_type,
+        PresenceType::Offline | PresenceType::Invisible
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{diff_presences, FriendActivity};
+    use crate::client::presence::{PresenceType, UserPresence};
+
+    fn presence(presence_type: PresenceType, universe_id: Option<u64>) -> UserPresence {
+        UserPresence {
+            user_id: 1,
+            presence_type,
+            universe_id,
+            game_id: universe_id.map(|id| format!("game-{id}")),
+            ..Default::default()
+        }
+    }
+
+    fn activities(previous: UserPresence, current: UserPresence) -> Vec<FriendActivity> {
+        let previous_presences = HashMap::from([(previous.user_id, previous)]);
+
+        diff_presences(&previous_presences, &[current])
+            .into_iter()
+            .map(|event| event.activity)
+            .collect()
+    }
+
+    #[test]
+    fn unknown_users_produce_no_events() {
+        let events = diff_presences(&HashMap::new(), &[presence(PresenceType::Online, None)]);
+
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn unchanged_presence_produces_no_events() {
+        assert!(activities(
+            presence(PresenceType::InGame, Some(10)),
+            presence(PresenceType::InGame, Some(10)),
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn coming_online_and_going_offline() {
+        assert_eq!(
+            activities(
+                presence(PresenceType::Offline, None),
+                presence(PresenceType::Online, None),
+            ),
+            vec![FriendActivity::CameOnline]
+        );
+        assert_eq!(
+            activities(
+                presence(PresenceType::Online, None),
+                presence(PresenceType::Offline, None),
+            ),
+            vec![FriendActivity::WentOffline]
+        );
+    }
+
+    #[test]
+    fn invisible_counts_as_offline() {
+        assert!(activities(
+            presence(PresenceType::Offline, None),
+            presence(PresenceType::Invisible, None),
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn joining_straight_from_offline() {
+        assert_eq!(
+            activities(
+                presence(PresenceType::Offline, None),
+                presence(PresenceType::InGame, Some(10)),
+            ),
+            vec![FriendActivity::CameOnline, FriendActivity::JoinedExperience]
+        );
+    }
+
+    #[test]
+    fn switching_games_leaves_and_joins() {
+        assert_eq!(
+            activities(
+                presence(PresenceType::InGame, Some(10)),
+                presence(PresenceType::InGame, Some(20)),
+            ),
+            vec![FriendActivity::LeftGame, FriendActivity::JoinedExperience]
+        );
+    }
+
+    #[test]
+    fn leaving_game_and_starting_studio() {
+        assert_eq!(
+            activities(
+                presence(PresenceType::InGame, Some(10)),
+                presence(PresenceType::InStudio, None),
+            ),
+            vec![FriendActivity::LeftGame, FriendActivity::StartedStudio]
+        );
+    }
+}
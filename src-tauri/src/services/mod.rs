@@ -0,0 +1,15 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub mod friend_export;
+pub mod friend_snapshots;
+pub mod friend_watcher;
+pub mod player_finder;
+pub mod presence_heartbeat;
+
+/// Current unix timestamp in seconds.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
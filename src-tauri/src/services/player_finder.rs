@@ -0,0 +1,245 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::{
+    client::{
+        games::{GameServer, ServerType, SortOrder},
+        thumbnails::{ThumbnailType, HEADSHOT_SIZE, THUMBNAILS_CHUNK_SIZE},
+        Limit, RobloxApi, RobloxError,
+    },
+    types::{PlayerFinderState, RobloxApiState},
+};
+
+/// Name of the Tauri event emitted after every scanned page.
+const PROGRESS_EVENT: &str = "find-player-progress";
+
+/// Pause between pages, so the scan doesn't run into rate limits in the first place.
+const PAGE_DELAY: Duration = Duration::from_millis(500);
+
+/// Pause after getting rate limited, multiplied by the amount of retries in a row.
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(5);
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
+/// Page cap used when none is given. Each page holds up to 100 servers.
+pub const DEFAULT_MAX_PAGES: usize = 50;
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FindPlayerProgress {
+    pub user_id: u64,
+    pub place_id: u64,
+    pub pages_scanned: usize,
+    pub servers_scanned: usize,
+    pub max_pages: usize,
+    /// Whether the scan is currently waiting out a rate limit.
+    pub rate_limited: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FoundServer {
+    pub place_id: u64,
+    /// Job id of the server, used to join it.
+    pub game_id: String,
+    pub server: GameServer,
+}
+
+/// Scans public servers of the place the user is in, looking for their headshot among the
+/// servers' player tokens. Returns `None` if the user wasn't found within `max_pages` pages.
+///
+/// If `place_id` is not given, the root place of the universe from the user's presence is used.
+/// The scan can be cancelled with [`PlayerFinderState::cancel`], a new scan for the same user
+/// cancels the previous one.
+pub async fn find<R: Runtime>(
+    app: &AppHandle<R>,
+    user_id: u64,
+    place_id: Option<u64>,
+    max_pages: usize,
+) -> Result<Option<FoundServer>, String> {
+    let finder_state = app.state::<PlayerFinderState>();
+    let cancelled = finder_state.start(user_id);
+
+    let result = scan(app, user_id, place_id, max_pages, &cancelled).await;
+
+    finder_state.finish(user_id, &cancelled);
+    result
+}
+
+async fn scan<R: Runtime>(
+    app: &AppHandle<R>,
+    user_id: u64,
+    place_id: Option<u64>,
+    max_pages: usize,
+    cancelled: &AtomicBool,
+) -> Result<Option<FoundServer>, String> {
+    let (place_id, headshot_url) = {
+        let state = app.state::<RobloxApiState>();
+        let client = state.0.read().await;
+
+        let place_id = match place_id {
+            Some(place_id) => place_id,
+            None => target_place_id(&client, user_id).await?,
+        };
+
+        let headshot_url = client
+            .completed_thumbnail_url_bulk(
+                vec![user_id],
+                HEADSHOT_SIZE,
+                ThumbnailType::AvatarHeadshot,
+            )
+            .await
+            .map_err(|err| err.to_string())?
+            .into_iter()
+            .next()
+            .flatten();
+
+        (place_id, headshot_url)
+    };
+
+    // Pending and blocked headshots share placeholder urls, which would match other players.
+    let Some(headshot_url) = headshot_url else {
+        return Err("User's headshot is not available yet, try again later".to_string());
+    };
+
+    let mut progress = FindPlayerProgress {
+        user_id,
+        place_id,
+        max_pages,
+        ..Default::default()
+    };
+    let mut cursor = None;
+    let mut rate_limit_retries = 0;
+
+    while progress.pages_scanned < max_pages {
+        if cancelled.load(Ordering::SeqCst) {
+            return Err("Scan cancelled".to_string());
+        }
+
+        // The client is locked per page, so logging in or out isn't blocked for the whole scan.
+        let page = {
+            let state = app.state::<RobloxApiState>();
+            let client = state.0.read().await;
+
+            scan_page(&client, place_id, cursor.clone(), &headshot_url).await
+        };
+
+        let (servers_count, found_server, next_page_cursor) = match page {
+            Ok(page) => page,
+            Err(RobloxError::TooManyRequests) if rate_limit_retries < MAX_RATE_LIMIT_RETRIES => {
+                rate_limit_retries += 1;
+                progress.rate_limited = true;
+                let _ = app.emit_all(PROGRESS_EVENT, progress.clone());
+
+                tokio::time::sleep(RATE_LIMIT_BACKOFF * rate_limit_retries).await;
+                continue;
+            }
+            Err(err) => return Err(err.to_string()),
+        };
+
+        rate_limit_retries = 0;
+        progress.rate_limited = false;
+        progress.pages_scanned += 1;
+        progress.servers_scanned += servers_count;
+        let _ = app.emit_all(PROGRESS_EVENT, progress.clone());
+
+        if let Some(server) = found_server {
+            return Ok(Some(FoundServer {
+                place_id,
+                game_id: server.id.clone(),
+                server,
+            }));
+        }
+
+        match next_page_cursor {
+            Some(next_page_cursor) => cursor = Some(next_page_cursor),
+            None => break,
+        }
+
+        tokio::time::sleep(PAGE_DELAY).await;
+    }
+
+    Ok(None)
+}
+
+/// Root place of the universe the user is in, according to their presence.
+async fn target_place_id(client: &RobloxApi, user_id: u64) -> Result<u64, String> {
+    let presence = client
+        .get_presence(vec![user_id])
+        .await
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .next()
+        .ok_or("User presence not found")?;
+
+    if let Some(place_id) = presence.place_id {
+        return Ok(place_id);
+    }
+
+    let universe_id = presence
+        .universe_id
+        .ok_or("User's experience is not visible")?;
+
+    client
+        .games_details(vec![universe_id])
+        .await
+        .map_err(|err| err.to_string())?
+        .first()
+        .map(|game| game.root_place_id)
+        .ok_or("Experience not found".to_string())
+}
+
+/// Scans a single page of servers. Returns the amount of servers on the page, the server
+/// with the headshot in it and the cursor of the next page.
+async fn scan_page(
+    client: &RobloxApi,
+    place_id: u64,
+    cursor: Option<String>,
+    headshot_url: &str,
+) -> Result<(usize, Option<GameServer>, Option<String>), RobloxError> {
+    let (servers, next_page_cursor) = client
+        .game_servers(
+            place_id,
+            Some(ServerType::Public),
+            Some(SortOrder::Descending),
+            Some(false),
+            Some(Limit::Hundred),
+            cursor,
+        )
+        .await?;
+
+    // Tokens of all servers, along with the index of the server they belong to.
+    let tokens = servers
+        .iter()
+        .enumerate()
+        .flat_map(|(i, server)| server.player_tokens.iter().map(move |token| (i, token)))
+        .collect::<Vec<_>>();
+
+    for chunk in tokens.chunks(THUMBNAILS_CHUNK_SIZE) {
+        // Tokens without a finished headshot are `None`, so they never match.
+        let urls = client
+            .completed_token_thumbnail_url_bulk(
+                chunk.iter().map(|(_, token)| token.to_string()).collect(),
+                HEADSHOT_SIZE,
+                ThumbnailType::AvatarHeadshot,
+            )
+            .await?;
+
+        if let Some(i) = urls
+            .iter()
+            .position(|url| url.as_deref() == Some(headshot_url))
+        {
+            let server_index = chunk[i].0;
+
+            return Ok((
+                servers.len(),
+                Some(servers[server_index].clone()),
+                next_page_cursor,
+            ));
+        }
+    }
+
+    Ok((servers.len(), None, next_page_cursor))
+}
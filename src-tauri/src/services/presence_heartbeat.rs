@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::types::{
+    HeartbeatPhase, PresenceHeartbeatSettings, PresenceHeartbeatState, PresenceHeartbeatStatus,
+    RobloxApiState,
+};
+
+/// The minimum interval between registrations, so the heartbeat doesn't get rate limited.
+pub const MIN_INTERVAL_SECS: u64 = 15;
+
+/// Name of the Tauri event emitted whenever the heartbeat status changes.
+const STATUS_EVENT: &str = "presence-heartbeat-status";
+
+/// Spawns the heartbeat, which registers the logged in user's presence once per
+/// configured interval, so they appear online on the website.
+pub fn spawn<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let settings = app
+                .state::<PresenceHeartbeatState>()
+                .settings
+                .read()
+                .await
+                .clone();
+
+            let status = tick(&app, &settings).await;
+            let heartbeat_state = app.state::<PresenceHeartbeatState>();
+            let mut current_status = heartbeat_state.status.write().await;
+
+            if *current_status != status {
+                *current_status = status.clone();
+                let _ = app.emit_all(STATUS_EVENT, status);
+            }
+
+            drop(current_status);
+
+            // Settings changes wake the heartbeat up, so they don't wait out the interval.
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_secs(settings.interval_secs)) => {}
+                _ = heartbeat_state.wake.notified() => {}
+            }
+        }
+    });
+}
+
+/// Registers presence unless the heartbeat is paused, returning the new status.
+async fn tick<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &PresenceHeartbeatSettings,
+) -> PresenceHeartbeatStatus {
+    let previous = app
+        .state::<PresenceHeartbeatState>()
+        .status
+        .read()
+        .await
+        .clone();
+
+    let status = |phase, last_error| PresenceHeartbeatStatus {
+        phase,
+        location: settings.location,
+        last_beat: previous.last_beat,
+        last_error,
+    };
+
+    if !settings.enabled {
+        return status(HeartbeatPhase::Disabled, None);
+    }
+
+    if settings.invisible {
+        return status(HeartbeatPhase::PausedInvisible, None);
+    }
+
+    if is_minimized(app) {
+        return status(HeartbeatPhase::PausedMinimized, None);
+    }
+
+    let state = app.state::<RobloxApiState>();
+    let client = state.0.read().await;
+
+    if client.cookie_string().await.is_err() {
+        return status(HeartbeatPhase::LoggedOut, None);
+    }
+
+    match client.register_presence(settings.location).await {
+        Ok(()) => PresenceHeartbeatStatus {
+            last_beat: Some(super::unix_now()),
+            ..status(HeartbeatPhase::Active, None)
+        },
+        Err(err) => status(HeartbeatPhase::Failed, Some(err.to_string())),
+    }
+}
+
+/// The app counts as minimized when none of its windows are shown. Without any windows
+/// (e.g. before the first one is created) it doesn't.
+fn is_minimized<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let windows = app.windows();
+
+    !windows.is_empty()
+        && windows.values().all(|window| {
+            window.is_minimized().unwrap_or(false) || !window.is_visible().unwrap_or(true)
+        })
+}
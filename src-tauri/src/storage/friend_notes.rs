@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use tauri::{AppHandle, Runtime};
+
+use crate::client::friends::{FriendNote, FriendUserInformation};
+
+/// Notes are stored per account, so switching accounts doesn't mix them up.
+fn key(account_id: u64) -> String {
+    format!("friend-notes-{}", account_id)
+}
+
+/// Loads all notes of the account, keyed by user id.
+pub fn load<R: Runtime>(app: &AppHandle<R>, account_id: u64) -> HashMap<u64, FriendNote> {
+    super::get(app, &key(account_id)).unwrap_or_default()
+}
+
+/// Sets a note of a user. Empty notes are removed.
+pub fn set<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+    user_id: u64,
+    note: FriendNote,
+) -> Result<(), String> {
+    let mut notes = load(app, account_id);
+
+    if note.is_empty() {
+        notes.remove(&user_id);
+    } else {
+        notes.insert(user_id, note);
+    }
+
+    super::set(app, &key(account_id), &notes)
+}
+
+/// Attaches notes to the friends they belong to.
+pub fn merge(friends: &mut [FriendUserInformation], notes: &HashMap<u64, FriendNote>) {
+    for friend in friends.iter_mut() {
+        friend.local_note = notes.get(&friend.user_id).cloned();
+    }
+}
+
+/// Moves pinned friends to the start, keeping the order of everyone else.
+pub fn sort_pinned_first(friends: &mut [FriendUserInformation]) {
+    friends.sort_by_key(|friend| !friend.local_note.as_ref().is_some_and(|note| note.pinned));
+}
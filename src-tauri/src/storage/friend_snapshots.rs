@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+use crate::client::friends::FriendUserInformation;
+
+/// How many snapshots are kept per account. The oldest ones are dropped first.
+const MAX_SNAPSHOTS: usize = 30;
+
+/// A friend as recorded in a snapshot. Only the fields needed to tell who they were.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct SnapshotUser {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+    pub is_terminated: bool,
+}
+
+impl From<FriendUserInformation> for SnapshotUser {
+    fn from(value: FriendUserInformation) -> Self {
+        Self {
+            user_id: value.user_id,
+            username: value.username,
+            display_name: value.display_name,
+            is_terminated: value.is_terminated,
+        }
+    }
+}
+
+/// Friends list of an account at some point in time.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FriendsSnapshot {
+    /// Unix timestamp in seconds.
+    pub taken_at: u64,
+    pub friends: Vec<SnapshotUser>,
+}
+
+/// Changes between two snapshots.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FriendsDiff {
+    /// When the older snapshot was taken, unix timestamp in seconds.
+    pub since: u64,
+    pub new_friends: Vec<SnapshotUser>,
+    /// Users who are no longer friends and whose accounts are still active.
+    pub unfriended: Vec<SnapshotUser>,
+    /// Users who were terminated, whether they are still on the friends list or not.
+    pub terminated: Vec<SnapshotUser>,
+}
+
+impl FriendsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.new_friends.is_empty() && self.unfriended.is_empty() && self.terminated.is_empty()
+    }
+}
+
+fn key(account_id: u64) -> String {
+    format!("friend-snapshots-{}", account_id)
+}
+
+/// Loads all snapshots of the account, oldest first.
+pub fn load<R: Runtime>(app: &AppHandle<R>, account_id: u64) -> Vec<FriendsSnapshot> {
+    super::get(app, &key(account_id)).unwrap_or_default()
+}
+
+/// Loads the most recent snapshot of the account.
+pub fn latest<R: Runtime>(app: &AppHandle<R>, account_id: u64) -> Option<FriendsSnapshot> {
+    load(app, account_id).pop()
+}
+
+/// Stores a new snapshot, dropping the oldest ones past [`MAX_SNAPSHOTS`].
+pub fn push<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+    snapshot: FriendsSnapshot,
+) -> Result<(), String> {
+    let mut snapshots = load(app, account_id);
+    snapshots.push(snapshot);
+
+    if snapshots.len() > MAX_SNAPSHOTS {
+        snapshots.drain(..snapshots.len() - MAX_SNAPSHOTS);
+    }
+
+    super::set(app, &key(account_id), &snapshots)
+}
+
+/// Compares two snapshots. Users missing from `current` are put into `unfriended`,
+/// callers that can look up the accounts should move terminated ones to `terminated`.
+pub fn diff(previous: &FriendsSnapshot, current: &FriendsSnapshot) -> FriendsDiff {
+    let previous_ids = previous
+        .friends
+        .iter()
+        .map(|friend| friend.user_id)
+        .collect::<HashSet<_>>();
+    let current_ids = current
+        .friends
+        .iter()
+        .map(|friend| friend.user_id)
+        .collect::<HashSet<_>>();
+    let previously_terminated = previous
+        .friends
+        .iter()
+        .filter(|friend| friend.is_terminated)
+        .map(|friend| friend.user_id)
+        .collect::<HashSet<_>>();
+
+    let new_friends = current
+        .friends
+        .iter()
+        .filter(|friend| !previous_ids.contains(&friend.user_id))
+        .cloned()
+        .collect();
+
+    let terminated = current
+        .friends
+        .iter()
+        .filter(|friend| friend.is_terminated && !previously_terminated.contains(&friend.user_id))
+        .cloned()
+        .collect();
+
+    let unfriended = previous
+        .friends
+        .iter()
+        .filter(|friend| !current_ids.contains(&friend.user_id))
+        .cloned()
+        .collect();
+
+    FriendsDiff {
+        since: previous.taken_at,
+        new_friends,
+        unfriended,
+        terminated,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, FriendsSnapshot, SnapshotUser};
+
+    fn user(user_id: u64, is_terminated: bool) -> SnapshotUser {
+        SnapshotUser {
+            user_id,
+            username: format!("user{user_id}"),
+            is_terminated,
+            ..Default::default()
+        }
+    }
+
+    fn snapshot(taken_at: u64, friends: Vec<SnapshotUser>) -> FriendsSnapshot {
+        FriendsSnapshot { taken_at, friends }
+    }
+
+    fn ids(users: &[SnapshotUser]) -> Vec<u64> {
+        users.iter().map(|user| user.user_id).collect()
+    }
+
+    #[test]
+    fn identical_snapshots_are_empty() {
+        let previous = snapshot(1, vec![user(1, false), user(2, false)]);
+        let current = snapshot(2, vec![user(2, false), user(1, false)]);
+
+        let diff = diff(&previous, &current);
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.since, 1);
+    }
+
+    #[test]
+    fn new_and_removed_friends() {
+        let previous = snapshot(1, vec![user(1, false), user(2, false)]);
+        let current = snapshot(2, vec![user(2, false), user(3, false)]);
+
+        let diff = diff(&previous, &current);
+
+        assert_eq!(ids(&diff.new_friends), vec![3]);
+        assert_eq!(ids(&diff.unfriended), vec![1]);
+        assert!(diff.terminated.is_empty());
+    }
+
+    #[test]
+    fn newly_terminated_friends_still_on_the_list() {
+        let previous = snapshot(1, vec![user(1, false), user(2, true)]);
+        let current = snapshot(2, vec![user(1, true), user(2, true)]);
+
+        let diff = diff(&previous, &current);
+
+        // Already terminated in the previous snapshot, so only user 1 is reported.
+        assert_eq!(ids(&diff.terminated), vec![1]);
+        assert!(diff.new_friends.is_empty());
+        assert!(diff.unfriended.is_empty());
+    }
+}
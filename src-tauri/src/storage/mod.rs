@@ -0,0 +1,55 @@
+use serde::{de::DeserializeOwned, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_store::{with_store, StoreCollection};
+
+pub mod friend_notes;
+pub mod friend_snapshots;
+pub mod preferences;
+pub mod presence_history;
+
+/// Same store file as the frontend uses, see `STORE_PATH` in `src/lib/constants.ts`.
+const STORE_PATH: &str = "store.dat";
+
+/// Reads a value from the store. Missing or malformed values are returned as `None`.
+fn get<R: Runtime, T: DeserializeOwned>(app: &AppHandle<R>, key: &str) -> Option<T> {
+    get_in(app, STORE_PATH, key)
+}
+
+/// Writes a value to the store and saves the store to the disk.
+fn set<R: Runtime, T: Serialize>(app: &AppHandle<R>, key: &str, value: &T) -> Result<(), String> {
+    set_in(app, STORE_PATH, key, value)
+}
+
+/// Same as [`get`], but for a store file other than the one shared with the frontend.
+fn get_in<R: Runtime, T: DeserializeOwned>(app: &AppHandle<R>, path: &str, key: &str) -> Option<T> {
+    with_store(
+        app.clone(),
+        app.state::<StoreCollection<R>>(),
+        path,
+        |store| Ok(store.get(key).cloned()),
+    )
+    .ok()
+    .flatten()
+    .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Same as [`set`], but for a store file other than the one shared with the frontend.
+fn set_in<R: Runtime, T: Serialize>(
+    app: &AppHandle<R>,
+    path: &str,
+    key: &str,
+    value: &T,
+) -> Result<(), String> {
+    let value = serde_json::to_value(value).map_err(|err| err.to_string())?;
+
+    with_store(
+        app.clone(),
+        app.state::<StoreCollection<R>>(),
+        path,
+        |store| {
+            store.insert(key.to_string(), value)?;
+            store.save()
+        },
+    )
+    .map_err(|err| err.to_string())
+}
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Runtime};
+
+use crate::types::PresenceHeartbeatSettings;
+
+const PRESENCE_HEARTBEAT_KEY: &str = "presence-heartbeat-settings";
+const FRIEND_WATCHER_INTERVAL_KEY: &str = "friend-watcher-interval-secs";
+
+/// Loads the presence heartbeat settings, falling back to the defaults (disabled).
+pub fn presence_heartbeat<R: Runtime>(app: &AppHandle<R>) -> PresenceHeartbeatSettings {
+    super::get(app, PRESENCE_HEARTBEAT_KEY).unwrap_or_default()
+}
+
+pub fn set_presence_heartbeat<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &PresenceHeartbeatSettings,
+) -> Result<(), String> {
+    super::set(app, PRESENCE_HEARTBEAT_KEY, settings)
+}
+
+/// Loads the friend watcher polling interval, `None` if it was never changed.
+pub fn friend_watcher_interval<R: Runtime>(app: &AppHandle<R>) -> Option<Duration> {
+    super::get(app, FRIEND_WATCHER_INTERVAL_KEY).map(Duration::from_secs)
+}
+
+pub fn set_friend_watcher_interval<R: Runtime>(
+    app: &AppHandle<R>,
+    interval: Duration,
+) -> Result<(), String> {
+    super::set(app, FRIEND_WATCHER_INTERVAL_KEY, &interval.as_secs())
+}
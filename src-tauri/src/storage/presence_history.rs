@@ -0,0 +1,478 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet},
+};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Runtime};
+
+use crate::client::presence::{PresenceType, UserPresence};
+
+/// History is kept in its own file, as it's rewritten on every poll of the friend watcher.
+const HISTORY_STORE_PATH: &str = "presence-history.dat";
+
+/// Sessions which ended before this long ago are dropped.
+const RETENTION_SECS: u64 = 30 * DAY_SECS;
+
+/// If nothing was observed for longer than this (e.g. the app was closed), ongoing sessions
+/// are closed at the last observation instead of being stretched over the gap.
+const MAX_OBSERVATION_GAP_SECS: u64 = 5 * 60;
+
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// A period during which a user's presence didn't change.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceSession {
+    pub user_id: u64,
+    pub presence_type: PresenceType,
+    pub universe_id: Option<u64>,
+    pub place_id: Option<u64>,
+    /// Unix timestamp in seconds.
+    pub started_at: u64,
+    /// Unix timestamp in seconds, `None` while the session is ongoing.
+    pub ended_at: Option<u64>,
+}
+
+/// Observed presence timeline of the friends and watched users of an account.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PresenceHistory {
+    /// Unix timestamp in seconds of the last recorded poll.
+    pub last_observed_at: u64,
+    pub sessions: Vec<PresenceSession>,
+}
+
+/// Total time spent in a universe by the observed users.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UniversePlaytime {
+    pub universe_id: u64,
+    pub total_secs: u64,
+    pub user_ids: Vec<u64>,
+}
+
+/// Time a user spent in each presence during a single (UTC) day.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyActivity {
+    /// Unix timestamp in seconds of the start of the day.
+    pub day: u64,
+    /// Includes time in game and in studio.
+    pub online_secs: u64,
+    pub in_game_secs: u64,
+    pub in_studio_secs: u64,
+}
+
+impl PresenceHistory {
+    /// Records a poll of presences, closing sessions whose presence changed and sessions of
+    /// users who are no longer polled.
+    pub fn record(&mut self, presences: &[UserPresence], now: u64) {
+        if self.last_observed_at != 0
+            && now.saturating_sub(self.last_observed_at) > MAX_OBSERVATION_GAP_SECS
+        {
+            let last_observed_at = self.last_observed_at;
+
+            for session in self.sessions.iter_mut() {
+                session.ended_at.get_or_insert(last_observed_at);
+            }
+        }
+
+        let polled_ids = presences
+            .iter()
+            .map(|presence| presence.user_id)
+            .collect::<HashSet<_>>();
+        let last_observed_at = self.last_observed_at;
+
+        // Users missing from the poll (e.g. unfriended or no longer watched) were last seen
+        // at the previous poll, so their sessions end there.
+        for session in self
+            .sessions
+            .iter_mut()
+            .filter(|session| session.ended_at.is_none() && !polled_ids.contains(&session.user_id))
+        {
+            session.ended_at = Some(last_observed_at.max(session.started_at));
+        }
+
+        let open_sessions = self
+            .sessions
+            .iter()
+            .enumerate()
+            .filter(|(_, session)| session.ended_at.is_none())
+            .map(|(i, session)| (session.user_id, i))
+            .collect::<HashMap<_, _>>();
+
+        for presence in presences {
+            // Invisible users look offline to everyone else, so they're recorded as such.
+            let presence_type = match &presence.presence_type {
+                PresenceType::Invisible => PresenceType::Offline,
+                presence_type => presence_type.clone(),
+            };
+
+            if let Some(&i) = open_sessions.get(&presence.user_id) {
+                let session = &mut self.sessions[i];
+
+                if session.presence_type == presence_type
+                    && session.universe_id == presence.universe_id
+                {
+                    continue;
+                }
+
+                session.ended_at = Some(now);
+            }
+
+            self.sessions.push(PresenceSession {
+                user_id: presence.user_id,
+                presence_type,
+                universe_id: presence.universe_id,
+                place_id: presence.place_id,
+                started_at: now,
+                ended_at: None,
+            });
+        }
+
+        self.last_observed_at = now;
+
+        let cutoff = now.saturating_sub(RETENTION_SECS);
+        self.sessions
+            .retain(|session| !matches!(session.ended_at, Some(ended_at) if ended_at < cutoff));
+    }
+
+    /// Sessions of a user which overlap with the period starting at `since`, oldest first.
+    pub fn timeline(&self, user_id: u64, since: u64) -> Vec<PresenceSession> {
+        self.sessions
+            .iter()
+            .filter(|session| session.user_id == user_id && self.end_of(session) >= since)
+            .cloned()
+            .collect()
+    }
+
+    /// When each of the users was last seen online, as unix timestamps in seconds.
+    /// Users who are online right now are reported at the last observation.
+    pub fn last_seen(&self, user_ids: &[u64]) -> HashMap<u64, u64> {
+        let user_ids = user_ids.iter().collect::<HashSet<_>>();
+        let mut last_seen = HashMap::new();
+
+        for session in self.sessions.iter().filter(|session| {
+            session.presence_type != PresenceType::Offline && user_ids.contains(&session.user_id)
+        }) {
+            let seen_at = last_seen.entry(session.user_id).or_insert(0);
+            *seen_at = (*seen_at).max(self.end_of(session));
+        }
+
+        last_seen
+    }
+
+    /// Universes the observed users played the most since `since`, most played first.
+    pub fn most_played(&self, since: u64) -> Vec<UniversePlaytime> {
+        let mut playtimes = HashMap::<u64, (u64, HashSet<u64>)>::new();
+
+        for session in self
+            .sessions
+            .iter()
+            .filter(|session| session.presence_type == PresenceType::InGame)
+        {
+            let Some(universe_id) = session.universe_id else {
+                continue;
+            };
+
+            let secs = self.overlap(session, since, self.last_observed_at);
+
+            if secs == 0 {
+                continue;
+            }
+
+            let (total_secs, user_ids) = playtimes.entry(universe_id).or_default();
+            *total_secs += secs;
+            user_ids.insert(session.user_id);
+        }
+
+        let mut playtimes = playtimes
+            .into_iter()
+            .map(|(universe_id, (total_secs, user_ids))| UniversePlaytime {
+                universe_id,
+                total_secs,
+                user_ids: user_ids.into_iter().collect(),
+            })
+            .collect::<Vec<_>>();
+
+        playtimes.sort_by_key(|playtime| Reverse(playtime.total_secs));
+        playtimes
+    }
+
+    /// Per-day activity of a user since `since`, suitable for charts. Days without any
+    /// activity are included, so the result has no gaps.
+    pub fn daily_activity(&self, user_id: u64, since: u64) -> Vec<DailyActivity> {
+        let first_day = since / DAY_SECS * DAY_SECS;
+        let mut days = BTreeMap::new();
+
+        let mut day = first_day;
+        while day <= self.last_observed_at {
+            days.insert(
+                day,
+                DailyActivity {
+                    day,
+                    ..Default::default()
+                },
+            );
+            day += DAY_SECS;
+        }
+
+        for session in self.sessions.iter().filter(|session| {
+            session.user_id == user_id && session.presence_type != PresenceType::Offline
+        }) {
+            for (day, activity) in days.iter_mut() {
+                let secs = self.overlap(session, *day, day + DAY_SECS);
+
+                if secs == 0 {
+                    continue;
+                }
+
+                activity.online_secs += secs;
+
+                match session.presence_type {
+                    PresenceType::InGame => activity.in_game_secs += secs,
+                    PresenceType::InStudio => activity.in_studio_secs += secs,
+                    _ => {}
+                }
+            }
+        }
+
+        days.into_values().collect()
+    }
+
+    fn end_of(&self, session: &PresenceSession) -> u64 {
+        session.ended_at.unwrap_or(self.last_observed_at)
+    }
+
+    /// Seconds of the session within `from..to`.
+    fn overlap(&self, session: &PresenceSession, from: u64, to: u64) -> u64 {
+        self.end_of(session)
+            .min(to)
+            .saturating_sub(session.started_at.max(from))
+    }
+}
+
+fn history_key(account_id: u64) -> String {
+    format!("presence-history-{}", account_id)
+}
+
+fn watched_users_key(account_id: u64) -> String {
+    format!("watched-users-{}", account_id)
+}
+
+pub fn load<R: Runtime>(app: &AppHandle<R>, account_id: u64) -> PresenceHistory {
+    super::get_in(app, HISTORY_STORE_PATH, &history_key(account_id)).unwrap_or_default()
+}
+
+pub fn save<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+    history: &PresenceHistory,
+) -> Result<(), String> {
+    super::set_in(app, HISTORY_STORE_PATH, &history_key(account_id), history)
+}
+
+/// Users whose presence is tracked in addition to the account's friends.
+pub fn watched_users<R: Runtime>(app: &AppHandle<R>, account_id: u64) -> HashSet<u64> {
+    super::get(app, &watched_users_key(account_id)).unwrap_or_default()
+}
+
+pub fn set_watched<R: Runtime>(
+    app: &AppHandle<R>,
+    account_id: u64,
+    user_id: u64,
+    watched: bool,
+) -> Result<(), String> {
+    let mut watched_users = watched_users(app, account_id);
+
+    if watched {
+        watched_users.insert(user_id);
+    } else {
+        watched_users.remove(&user_id);
+    }
+
+    super::set(app, &watched_users_key(account_id), &watched_users)
+}
+
+/// Start of the period covering the last `days` days.
+pub fn days_ago(now: u64, days: u64) -> u64 {
+    now.saturating_sub(days * DAY_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{DailyActivity, PresenceHistory, DAY_SECS};
+    use crate::client::presence::{PresenceType, UserPresence};
+
+    fn presence(
+        user_id: u64,
+        presence_type: PresenceType,
+        universe_id: Option<u64>,
+    ) -> UserPresence {
+        UserPresence {
+            user_id,
+            presence_type,
+            universe_id,
+            ..Default::default()
+        }
+    }
+
+    /// Sessions of a user as `(presence_type, started_at, ended_at)`.
+    fn sessions(history: &PresenceHistory, user_id: u64) -> Vec<(PresenceType, u64, Option<u64>)> {
+        history
+            .timeline(user_id, 0)
+            .into_iter()
+            .map(|session| (session.presence_type, session.started_at, session.ended_at))
+            .collect()
+    }
+
+    #[test]
+    fn record_keeps_unchanged_sessions_open() {
+        let mut history = PresenceHistory::default();
+        history.record(&[presence(1, PresenceType::Online, None)], 100);
+        history.record(&[presence(1, PresenceType::Online, None)], 160);
+
+        assert_eq!(
+            sessions(&history, 1),
+            vec![(PresenceType::Online, 100, None)]
+        );
+    }
+
+    #[test]
+    fn record_closes_changed_sessions() {
+        let mut history = PresenceHistory::default();
+        history.record(&[presence(1, PresenceType::InGame, Some(10))], 100);
+        history.record(&[presence(1, PresenceType::InGame, Some(20))], 160);
+        history.record(&[presence(1, PresenceType::Invisible, None)], 220);
+
+        assert_eq!(
+            sessions(&history, 1),
+            vec![
+                (PresenceType::InGame, 100, Some(160)),
+                (PresenceType::InGame, 160, Some(220)),
+                (PresenceType::Offline, 220, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn record_closes_sessions_of_users_missing_from_the_poll() {
+        let mut history = PresenceHistory::default();
+        history.record(
+            &[
+                presence(1, PresenceType::Online, None),
+                presence(2, PresenceType::Online, None),
+            ],
+            100,
+        );
+        history.record(&[presence(1, PresenceType::Online, None)], 160);
+
+        assert_eq!(
+            sessions(&history, 2),
+            vec![(PresenceType::Online, 100, Some(100))]
+        );
+        assert_eq!(
+            sessions(&history, 1),
+            vec![(PresenceType::Online, 100, None)]
+        );
+    }
+
+    #[test]
+    fn record_closes_sessions_at_the_last_observation_after_a_gap() {
+        let mut history = PresenceHistory::default();
+        history.record(&[presence(1, PresenceType::Online, None)], 100);
+        history.record(&[presence(1, PresenceType::Online, None)], 100 + DAY_SECS);
+
+        assert_eq!(
+            sessions(&history, 1),
+            vec![
+                (PresenceType::Online, 100, Some(100)),
+                (PresenceType::Online, 100 + DAY_SECS, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn timeline_skips_sessions_ended_before_since() {
+        let mut history = PresenceHistory::default();
+        history.record(&[presence(1, PresenceType::Online, None)], 100);
+        history.record(&[presence(1, PresenceType::Offline, None)], 200);
+        history.record(&[presence(1, PresenceType::Offline, None)], 300);
+
+        let timeline = history.timeline(1, 250);
+
+        assert_eq!(timeline.len(), 1);
+        assert_eq!(timeline[0].presence_type, PresenceType::Offline);
+        assert!(history.timeline(2, 0).is_empty());
+    }
+
+    #[test]
+    fn last_seen_uses_the_end_of_online_sessions() {
+        let mut history = PresenceHistory::default();
+        history.record(
+            &[
+                presence(1, PresenceType::Online, None),
+                presence(2, PresenceType::Offline, None),
+                presence(3, PresenceType::InGame, Some(10)),
+            ],
+            100,
+        );
+        history.record(
+            &[
+                presence(1, PresenceType::Offline, None),
+                presence(2, PresenceType::Offline, None),
+                presence(3, PresenceType::InGame, Some(10)),
+            ],
+            200,
+        );
+
+        // User 3 is still online, so they're reported at the last observation.
+        assert_eq!(
+            history.last_seen(&[1, 2, 3]),
+            HashMap::from([(1, 200), (3, 200)])
+        );
+    }
+
+    #[test]
+    fn daily_activity_splits_sessions_across_days() {
+        let mut history = PresenceHistory::default();
+        history.record(
+            &[presence(1, PresenceType::InGame, Some(10))],
+            DAY_SECS - 120,
+        );
+        history.record(
+            &[presence(1, PresenceType::InGame, Some(10))],
+            DAY_SECS - 60,
+        );
+        history.record(&[presence(1, PresenceType::InStudio, None)], DAY_SECS + 60);
+        history.record(&[presence(1, PresenceType::Offline, None)], DAY_SECS + 120);
+
+        assert_eq!(
+            history.daily_activity(1, 0),
+            vec![
+                DailyActivity {
+                    day: 0,
+                    online_secs: 120,
+                    in_game_secs: 120,
+                    in_studio_secs: 0,
+                },
+                DailyActivity {
+                    day: DAY_SECS,
+                    online_secs: 120,
+                    in_game_secs: 60,
+                    in_studio_secs: 60,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn daily_activity_includes_days_without_activity() {
+        let mut history = PresenceHistory::default();
+        history.record(&[presence(1, PresenceType::Offline, None)], 2 * DAY_SECS);
+
+        let days = history.daily_activity(1, 0);
+
+        assert_eq!(days.len(), 3);
+        assert!(days.iter().all(|day| day.online_secs == 0));
+    }
+}
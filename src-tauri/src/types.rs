@@ -1,10 +1,163 @@
-use crate::client::RobloxApi;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use crate::client::{presence::PresenceLocation, RobloxApi};
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 
 #[derive(Default)]
 pub struct RobloxApiState(pub RwLock<RobloxApi>);
 
+/// Settings of the friend activity watcher.
+pub struct FriendWatcherState {
+    /// How often friends' presences are polled.
+    pub interval: RwLock<Duration>,
+}
+
+impl FriendWatcherState {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: RwLock::new(interval),
+        }
+    }
+}
+
+impl Default for FriendWatcherState {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(30))
+    }
+}
+
+/// Cancellation flags of running player finder scans, keyed by the user id being searched for,
+/// so cancelling one scan doesn't affect the others.
+#[derive(Default)]
+pub struct PlayerFinderState(pub Mutex<HashMap<u64, Arc<AtomicBool>>>);
+
+impl PlayerFinderState {
+    /// Registers a new scan for the user, cancelling the previous one for them, if any.
+    pub fn start(&self, user_id: u64) -> Arc<AtomicBool> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        if let Some(previous) = self.0.lock().unwrap().insert(user_id, cancelled.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
+
+        cancelled
+    }
+
+    pub fn cancel(&self, user_id: u64) {
+        if let Some(cancelled) = self.0.lock().unwrap().get(&user_id) {
+            cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Forgets a finished scan, unless it was already replaced by a newer one.
+    pub fn finish(&self, user_id: u64, cancelled: &Arc<AtomicBool>) {
+        let mut scans = self.0.lock().unwrap();
+
+        if scans
+            .get(&user_id)
+            .is_some_and(|current| Arc::ptr_eq(current, cancelled))
+        {
+            scans.remove(&user_id);
+        }
+    }
+}
+
+/// How many recently joined servers are remembered.
+const RECENT_SERVERS_CAPACITY: usize = 10;
+
+/// Ids of servers recently joined from the app, newest first. A std mutex is used, as
+/// servers are also recorded from sync commands.
+#[derive(Default)]
+pub struct RecentServersState(pub Mutex<VecDeque<String>>);
+
+impl RecentServersState {
+    pub fn record(&self, game_id: String) {
+        let mut recent_servers = self.0.lock().unwrap();
+
+        recent_servers.retain(|id| *id != game_id);
+        recent_servers.push_front(game_id);
+        recent_servers.truncate(RECENT_SERVERS_CAPACITY);
+    }
+
+    pub fn ids(&self) -> Vec<String> {
+        self.0.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// Settings and last reported status of the presence heartbeat.
+#[derive(Default)]
+pub struct PresenceHeartbeatState {
+    pub settings: RwLock<PresenceHeartbeatSettings>,
+    pub status: RwLock<PresenceHeartbeatStatus>,
+
+    /// Wakes the heartbeat up early, so changed settings apply right away.
+    pub wake: Notify,
+}
+
+impl PresenceHeartbeatState {
+    pub fn new(settings: PresenceHeartbeatSettings) -> Self {
+        Self {
+            settings: RwLock::new(settings),
+            ..Default::default()
+        }
+    }
+}
+
+/// Stored with [`crate::storage::preferences`], so they survive restarts.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PresenceHeartbeatSettings {
+    pub enabled: bool,
+    /// How often the presence is registered, in seconds.
+    pub interval_secs: u64,
+    pub location: PresenceLocation,
+    /// Stops registering presence, so the user appears offline.
+    pub invisible: bool,
+}
+
+impl Default for PresenceHeartbeatSettings {
+    fn default() -> Self {
+        Self {
+            // Registering presence marks the user online, so it's opt-in.
+            enabled: false,
+            interval_secs: 60,
+            location: PresenceLocation::default(),
+            invisible: false,
+        }
+    }
+}
+
+/// What the presence heartbeat did on its last tick.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeartbeatPhase {
+    /// Hasn't ticked yet.
+    #[default]
+    Starting,
+    Active,
+    Disabled,
+    PausedMinimized,
+    PausedInvisible,
+    LoggedOut,
+    Failed,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresenceHeartbeatStatus {
+    pub phase: HeartbeatPhase,
+    pub location: PresenceLocation,
+    /// Unix timestamp in seconds of the last successful registration.
+    pub last_beat: Option<u64>,
+    pub last_error: Option<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ClientInfo {
     pub user_id: u64,
@@ -13,9 +166,12 @@ pub struct ClientInfo {
     pub robux: u64,
 }
 
+/// Result of a single item of a bulk action.
 #[derive(Serialize, Deserialize)]
-pub struct UserProfileStats {
-    pub friends: usize,
-    pub followers: usize,
-    pub followings: usize,
+pub struct BulkActionResult {
+    pub user_id: u64,
+    pub success: bool,
+    /// The item was left alone on purpose, e.g. a friend request from a blocked user.
+    pub skipped: bool,
+    pub error: Option<String>,
 }
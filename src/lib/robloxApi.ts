@@ -2,20 +2,55 @@ import { invoke, type InvokeArgs } from "@tauri-apps/api/tauri";
 import chunk from "lodash.chunk";
 
 import {
+    ExportFormat,
+    ExportList,
     FriendStatus,
+    FriendshipOriginSource,
+    Limit,
+    SortOrder,
     ThumbnailSize,
     ThumbnailType,
+    type BulkActionResult,
     type ClientInfo,
+    type FoundServer,
+    type FriendNote,
+    type FriendRecommendation,
+    type FriendsActivity,
+    type FriendsDiff,
+    type FriendsSnapshot,
+    type FriendRequest,
+    type FriendRequestFilter,
     type UserInformation,
     type GameDetails,
+    type MutualFriend,
+    type OnlineFriend,
     type GameMedia,
     type GameServer,
+    type GameServersOptions,
+    type DailyActivity,
     type PlaceDetails,
+    type PresenceSession,
+    type PrivateServer,
+    type PrivateServerDetails,
+    type PrivateServerOptions,
+    type PrivateServerPermissions,
+    type PrivateServerPermissionsUpdate,
+    type PrivateServerUpdate,
+    type UniversePlaytime,
+    type PresenceHeartbeatSettings,
+    type PresenceHeartbeatStatus,
+    type PromotionChannels,
+    type RankedServer,
+    type ServerPlayer,
+    type ServerRankingOptions,
     type RecommendationsTopic,
+    type User,
     type UserDetails,
+    type UserProfile,
     type UserPresence,
     type UserProfileStats,
-    type SearchResult
+    type SearchResult,
+    type UserSearchResult
 } from "$lib/typings";
 
 // Roblox api
@@ -32,8 +67,40 @@ export const robloxApi = {
         return this._invoke<boolean>("is_authed");
     },
 
-    updatePresence() {
-        return this._invoke<void>("presence");
+    getPresenceHeartbeatSettings() {
+        return this._invoke<PresenceHeartbeatSettings>("presence_heartbeat_settings");
+    },
+
+    setPresenceHeartbeatSettings(settings: PresenceHeartbeatSettings) {
+        return this._invoke<void>("set_presence_heartbeat_settings", { settings });
+    },
+
+    getPresenceHeartbeatStatus() {
+        return this._invoke<PresenceHeartbeatStatus>("presence_heartbeat_status");
+    },
+
+    getPresenceTimeline(userId: number, days?: number) {
+        return this._invoke<PresenceSession[]>("presence_timeline", { userId, days });
+    },
+
+    getLastSeen(userIds: number[]) {
+        return this._invoke<Record<number, number>>("last_seen", { userIds });
+    },
+
+    getMostPlayedWithFriends(days?: number) {
+        return this._invoke<UniversePlaytime[]>("most_played_with_friends", { days });
+    },
+
+    getUserActivity(userId: number, days?: number) {
+        return this._invoke<DailyActivity[]>("user_activity", { userId, days });
+    },
+
+    getWatchedUsers() {
+        return this._invoke<number[]>("watched_users");
+    },
+
+    setUserWatched(userId: number, watched: boolean) {
+        return this._invoke<void>("set_user_watched", { userId, watched });
     },
 
     getMe() {
@@ -44,30 +111,144 @@ export const robloxApi = {
         return this._invoke<UserDetails>("get_user", { userId });
     },
 
+    getUsernameHistory(userId: number, limit?: Limit, cursor?: string) {
+        return this._invoke<[string[], string | null]>("username_history", {
+            userId,
+            limit,
+            cursor,
+        });
+    },
+
     getUserStats(userId: number) {
         return this._invoke<UserProfileStats>("get_user_stats", { userId });
     },
 
+    getUserProfile(userId: number) {
+        return this._invoke<UserProfile>("get_user_profile", { userId });
+    },
+
     getFriendStatus(userId: number) {
         return this._invoke<FriendStatus>("friend_status", { userId });
     },
 
+    getFriendStatuses(userIds: number[]) {
+        return this._invoke<Record<number, FriendStatus>>("friend_statuses", { userIds });
+    },
+
     getFriendsList() {
         return this._invoke<UserInformation[]>("friends_list");
     },
 
+    getFriendNotes() {
+        return this._invoke<Record<number, FriendNote>>("friend_notes");
+    },
+
+    setFriendNote(userId: number, note: FriendNote) {
+        return this._invoke<void>("set_friend_note", { userId, note });
+    },
+
+    setFriendPinned(userId: number, pinned: boolean) {
+        return this._invoke<void>("set_friend_pinned", { userId, pinned });
+    },
+
+    getOnlineFriends() {
+        return this._invoke<OnlineFriend[]>("online_friends");
+    },
+
     getUsersFriendsList(userId: number) {
         return this._invoke<UserInformation[]>("users_friends_list", { userId });
     },
 
-    friend(userId: number) {
-        return this._invoke<void>("friend", { userId });
+    getMutualFriends(userId: number) {
+        return this._invoke<MutualFriend[]>("mutual_friends", { userId });
+    },
+
+    getFriendsActivity() {
+        return this._invoke<FriendsActivity[]>("friends_activity");
+    },
+
+    getFriendRecommendations() {
+        return this._invoke<FriendRecommendation[]>("friend_recommendations");
+    },
+
+    dismissFriendRecommendation(userId: number) {
+        return this._invoke<void>("dismiss_friend_recommendation", { userId });
+    },
+
+    getFollowers(userId: number, limit?: Limit, sortOrder?: SortOrder, cursor?: string) {
+        return this._invoke<[UserInformation[], string | null]>("followers", {
+            userId,
+            limit,
+            sortOrder,
+            cursor,
+        });
+    },
+
+    getFollowings(userId: number, limit?: Limit, sortOrder?: SortOrder, cursor?: string) {
+        return this._invoke<[UserInformation[], string | null]>("followings", {
+            userId,
+            limit,
+            sortOrder,
+            cursor,
+        });
+    },
+
+    exportFriends(userId: number, list: ExportList, format: ExportFormat) {
+        return this._invoke<string>("export_friends", { userId, list, format });
+    },
+
+    getFriendsSnapshots() {
+        return this._invoke<FriendsSnapshot[]>("friends_snapshots");
+    },
+
+    takeFriendsSnapshot() {
+        return this._invoke<FriendsDiff | null>("take_friends_snapshot");
+    },
+
+    getFriendsSnapshotDiff() {
+        return this._invoke<FriendsDiff | null>("friends_snapshot_diff");
+    },
+
+    follow(userId: number) {
+        return this._invoke<void>("follow", { userId });
+    },
+
+    unfollow(userId: number) {
+        return this._invoke<void>("unfollow", { userId });
+    },
+
+    friend(userId: number, originSource: FriendshipOriginSource = FriendshipOriginSource.UserProfile) {
+        return this._invoke<void>("friend", { userId, originSource });
     },
 
     unfriend(userId: number) {
         return this._invoke<void>("unfriend", { userId });
     },
 
+    getFriendRequests(limit?: Limit, cursor?: string, excludeBlocked?: boolean) {
+        return this._invoke<[FriendRequest[], string | null]>("friend_requests", {
+            limit,
+            cursor,
+            excludeBlocked,
+        });
+    },
+
+    getAllFriendRequests(excludeBlocked?: boolean) {
+        return this._invoke<FriendRequest[]>("all_friend_requests", { excludeBlocked });
+    },
+
+    getPendingFriendRequests() {
+        return this._invoke<number>("pending_friend_requests");
+    },
+
+    acceptFriendRequests(filter: FriendRequestFilter) {
+        return this._invoke<BulkActionResult[]>("accept_friend_requests", { filter });
+    },
+
+    declineFriendRequests(filter: FriendRequestFilter) {
+        return this._invoke<BulkActionResult[]>("decline_friend_requests", { filter });
+    },
+
     acceptFriendRequest(userId: number) {
         return this._invoke<void>("accept_friend_request", { userId });
     },
@@ -76,6 +257,50 @@ export const robloxApi = {
         return this._invoke<void>("decline_friend_request", { userId });
     },
 
+    setDescription(description: string) {
+        return this._invoke<string>("set_description", { description });
+    },
+
+    validateDisplayName(displayName: string) {
+        return this._invoke<void>("validate_display_name", { displayName });
+    },
+
+    setDisplayName(displayName: string) {
+        return this._invoke<void>("set_display_name", { displayName });
+    },
+
+    getPromotionChannels() {
+        return this._invoke<PromotionChannels>("promotion_channels");
+    },
+
+    setPromotionChannels(promotionChannels: PromotionChannels) {
+        return this._invoke<void>("set_promotion_channels", { promotionChannels });
+    },
+
+    getBlockedUsers() {
+        return this._invoke<User[]>("blocked_users");
+    },
+
+    isBlocked(userId: number) {
+        return this._invoke<boolean>("is_blocked", { userId });
+    },
+
+    block(userId: number) {
+        return this._invoke<void>("block", { userId });
+    },
+
+    unblock(userId: number) {
+        return this._invoke<void>("unblock", { userId });
+    },
+
+    getFriendWatcherInterval() {
+        return this._invoke<number>("friend_watcher_interval");
+    },
+
+    setFriendWatcherInterval(intervalSecs: number) {
+        return this._invoke<void>("set_friend_watcher_interval", { intervalSecs });
+    },
+
     getPresences(userIds: number[]) {
         return this._invoke<UserPresence[]>("get_presences", {
             userIds,
@@ -104,11 +329,17 @@ export const robloxApi = {
         });
     },
 
-    getGameServers(placeId: number, serversType: "Public" | "Friends", cursor?: string) {
+    getGameServers(
+        placeId: number,
+        serversType: "Public" | "Friends",
+        cursor?: string,
+        options: GameServersOptions = {},
+    ) {
         return this._invoke<[GameServer[], string | null]>("game_servers", {
             placeId,
             serversType,
-            cursor
+            cursor,
+            ...options,
         });
     },
 
@@ -153,12 +384,47 @@ export const robloxApi = {
         })
     },
 
+    // Only finished thumbnails have a url, in the same order as `ids`.
+    getCompletedThumbnailsUrls(
+        ids: number[],
+        thumbnailSize: ThumbnailSize,
+        thumbnailType: ThumbnailType
+    ) {
+        return this._invoke<(string | null)[]>("completed_thumbnail_url_bulk", {
+            ids,
+            thumbnailSize,
+            thumbnailType
+        })
+    },
+
+    // Only finished thumbnails have a url, in the same order as `tokens`.
+    getCompletedTokensThumbnailsUrls(
+        tokens: string[],
+        thumbnailSize: ThumbnailSize,
+        thumbnailType: ThumbnailType
+    ) {
+        return this._invoke<(string | null)[]>("completed_token_thumbnail_url_bulk", {
+            tokens,
+            thumbnailSize,
+            thumbnailType
+        })
+    },
+
     omniSearch(query: string) {
         return this._invoke<SearchResult[]>("omni_search", {
             queryString: query,
         });
     },
 
+    userSearch(keyword: string, limit?: Limit, cursor?: string, excludeBlocked?: boolean) {
+        return this._invoke<[UserSearchResult[], string | null]>("user_search", {
+            keyword,
+            limit,
+            cursor,
+            excludeBlocked,
+        });
+    },
+
     playPlace(placeId: number) {
         return this._invoke<GameDetails>("open_place", {
             placeId,
@@ -171,4 +437,102 @@ export const robloxApi = {
             gameId,
         });
     },
+
+    playPrivateServer(placeId: number, accessCode: string) {
+        return this._invoke<void>("open_private_server", {
+            placeId,
+            accessCode,
+        });
+    },
+
+    getPrivateServers(placeId: number, cursor?: string, limit?: Limit) {
+        return this._invoke<[PrivateServer[], string | null]>("private_servers", {
+            placeId,
+            limit,
+            cursor,
+        });
+    },
+
+    getPrivateServerOptions(universeId: number) {
+        return this._invoke<PrivateServerOptions>("private_server_options", {
+            universeId,
+        });
+    },
+
+    // expectedPrice has to match the current price, so the user is never overcharged
+    createPrivateServer(
+        universeId: number,
+        name: string,
+        expectedPrice: number,
+        purchaseConfirmed: boolean,
+    ) {
+        return this._invoke<PrivateServerDetails>("create_private_server", {
+            universeId,
+            name,
+            expectedPrice,
+            purchaseConfirmed,
+        });
+    },
+
+    getPrivateServer(vipServerId: number) {
+        return this._invoke<PrivateServerDetails>("private_server", {
+            vipServerId,
+        });
+    },
+
+    updatePrivateServer(vipServerId: number, update: PrivateServerUpdate) {
+        return this._invoke<PrivateServerDetails>("update_private_server", {
+            vipServerId,
+            update,
+        });
+    },
+
+    setPrivateServerPermissions(
+        vipServerId: number,
+        update: PrivateServerPermissionsUpdate,
+    ) {
+        return this._invoke<PrivateServerPermissions>(
+            "set_private_server_permissions",
+            { vipServerId, update },
+        );
+    },
+
+    getServerPlayers(playerTokens: string[]) {
+        return this._invoke<ServerPlayer[]>("server_players", { playerTokens });
+    },
+
+    getRankedServers(placeId: number, options?: ServerRankingOptions, excludeRecent?: boolean) {
+        return this._invoke<RankedServer[]>("ranked_servers", {
+            placeId,
+            options,
+            excludeRecent,
+        });
+    },
+
+    playBestServer(placeId: number, options?: ServerRankingOptions, excludeRecent?: boolean) {
+        return this._invoke<RankedServer>("open_best_server", {
+            placeId,
+            options,
+            excludeRecent,
+        });
+    },
+
+    // Progress is reported through "find-player-progress" events (FindPlayerProgress)
+    findPlayer(userId: number, placeId?: number, maxPages?: number) {
+        return this._invoke<FoundServer | null>("find_player", {
+            userId,
+            placeId,
+            maxPages,
+        });
+    },
+
+    cancelFindPlayer(userId: number) {
+        return this._invoke<void>("cancel_find_player", { userId });
+    },
+
+    joinUser(userId: number) {
+        return this._invoke<void>("join_user", {
+            userId,
+        });
+    },
 }
@@ -19,6 +19,24 @@ export interface ClientInfo {
 
 // Api Typings
 
+export const enum Limit {
+    Ten = "Ten",
+    TwentyFive = "TwentyFive",
+    Fifty = "Fifty",
+    Hundred = "Hundred",
+}
+
+export const enum SortOrder {
+    Ascending = "Ascending",
+    Descending = "Descending",
+}
+
+export interface User {
+    user_id: number,
+    username: string,
+    display_name: string,
+}
+
 export interface UserDetails {
     id: number,
     username: string,
@@ -33,12 +51,51 @@ export interface UserDetails {
     has_verified_badge: boolean,
 }
 
+export interface UserSearchResult {
+    user_id: number,
+    username: string,
+    display_name: string,
+    has_verified_badge: boolean,
+
+    // Previous usernames that matched the keyword.
+    previous_usernames: string[],
+}
+
 export interface UserProfileStats {
     friends: number,
     followers: number,
     followings: number
 }
 
+export interface RobloxBadge {
+    id: number,
+    name: string,
+    description: string,
+    image_url: string,
+}
+
+// Unified model of a user. Optional parts are missing if they could not be fetched.
+export interface UserProfile extends UserDetails {
+    presence?: UserPresence,
+    stats?: UserProfileStats,
+    badges?: RobloxBadge[],
+    friend_status?: FriendStatus,
+
+    // Up to 100 most recent previous usernames, newest first
+    previous_usernames?: string[],
+}
+
+export interface PromotionChannels {
+    // NoOne, Friends, FriendsAndFollowing, FriendsFollowingAndFollowers, AllAuthenticatedUsers or AllUsers
+    visibility_privacy: string,
+
+    facebook?: string,
+    twitter?: string,
+    youtube?: string,
+    twitch?: string,
+    guilded?: string,
+}
+
 export interface UserInformation {
     user_id: number;
     username: string;
@@ -50,12 +107,86 @@ export interface UserInformation {
     created: string;
 
     presence_type: string;
+    is_online: boolean;
 
     // Whether the user is banned/terminated.
     is_terminated: boolean;
 
     // The user's verified badge status.
     has_verified_badge: boolean;
+
+    // How often the user plays with the logged in user. Higher is more frequent.
+    friend_frequent_score: number;
+    friend_frequent_rank: number;
+
+    // Private note stored locally by rsblox
+    local_note?: FriendNote;
+}
+
+export interface FriendNote {
+    nickname?: string;
+    note?: string;
+    tags: string[];
+
+    // Pinned friends are sorted first
+    pinned: boolean;
+}
+
+export interface MutualFriend {
+    friend: UserInformation;
+    headshot_url?: string;
+}
+
+export interface OnlineFriend {
+    user_id: number;
+    username: string;
+    display_name: string;
+
+    presence: UserPresence;
+}
+
+export enum FriendshipOriginSource {
+    Unknown = "Unknown",
+    PlayerSearch = "PlayerSearch",
+    QrCode = "QrCode",
+    InGame = "InGame",
+    UserProfile = "UserProfile",
+    QqContactImporter = "QqContactImporter",
+    WeChatContactImporter = "WeChatContactImporter",
+    ProfileShare = "ProfileShare",
+    PhoneContactImporter = "PhoneContactImporter",
+    FriendRecommendations = "FriendRecommendations",
+}
+
+export interface FriendRequest {
+    user_id: number,
+    username: string,
+    display_name: string,
+    description?: string,
+    created: string,
+    is_terminated: boolean,
+    has_verified_badge: boolean,
+    mutual_friends_list: string[],
+
+    origin_source_type: FriendshipOriginSource,
+
+    // Universe id which the request was sent in. 0 by default
+    source_universe_id: number,
+    sent_at: string,
+}
+
+// Empty filter matches every request
+export interface FriendRequestFilter {
+    mutual_friends_only: boolean,
+    source_universe_id?: number,
+    user_ids?: number[],
+}
+
+export interface BulkActionResult {
+    user_id: number,
+    success: boolean,
+    skipped: boolean,
+    error?: string,
 }
 
 export enum FriendStatus {
@@ -84,6 +215,146 @@ export interface UserPresence {
     universe_id?: number;
 }
 
+// Each activity is emitted as its own event:
+// friend-came-online, friend-joined-experience, friend-left-game, friend-went-offline, friend-started-studio
+export enum FriendActivity {
+    CameOnline = "CameOnline",
+    JoinedExperience = "JoinedExperience",
+    LeftGame = "LeftGame",
+    WentOffline = "WentOffline",
+    StartedStudio = "StartedStudio",
+}
+
+export interface FriendActivityEvent {
+    user_id: number;
+    activity: FriendActivity;
+    previous: UserPresence;
+    current: UserPresence;
+}
+
+export interface FriendsActivityServer {
+    place_id: number;
+    game_id: string;
+    user_ids: number[];
+}
+
+export interface FriendsActivity {
+    universe_id: number;
+    game?: GameDetails;
+    icon_url?: string;
+
+    // Every friend in the experience, including those with hidden servers
+    user_ids: number[];
+
+    // Busiest first
+    servers: FriendsActivityServer[];
+}
+
+export interface FriendRecommendation {
+    user_id: number;
+    username: string;
+    display_name: string;
+    has_verified_badge: boolean;
+
+    // Usernames of mutual friends
+    mutual_friends: string[];
+    context_type?: string;
+    thumbnail_token?: string;
+}
+
+export enum PresenceLocation {
+    Home = "Home",
+    Games = "Games",
+    Avatar = "Avatar",
+    Catalog = "Catalog",
+    Friends = "Friends",
+    Profile = "Profile",
+    Groups = "Groups",
+}
+
+export interface PresenceHeartbeatSettings {
+    enabled: boolean;
+    interval_secs: number;
+    location: PresenceLocation;
+    invisible: boolean;
+}
+
+export enum HeartbeatPhase {
+    Starting = "Starting",
+    Active = "Active",
+    Disabled = "Disabled",
+    PausedMinimized = "PausedMinimized",
+    PausedInvisible = "PausedInvisible",
+    LoggedOut = "LoggedOut",
+    Failed = "Failed",
+}
+
+export interface PresenceHeartbeatStatus {
+    phase: HeartbeatPhase;
+    location: PresenceLocation;
+
+    // Unix timestamp in seconds of the last successful registration
+    last_beat?: number;
+    last_error?: string;
+}
+
+export interface PresenceSession {
+    user_id: number;
+    presence_type: string;
+    universe_id?: number;
+    place_id?: number;
+
+    // Unix timestamps in seconds, ended_at is missing while ongoing
+    started_at: number;
+    ended_at?: number;
+}
+
+export interface UniversePlaytime {
+    universe_id: number;
+    total_secs: number;
+    user_ids: number[];
+}
+
+export interface DailyActivity {
+    // Unix timestamp in seconds of the start of the day (UTC)
+    day: number;
+    online_secs: number;
+    in_game_secs: number;
+    in_studio_secs: number;
+}
+
+export enum ExportList {
+    Friends = "Friends",
+    Followers = "Followers",
+    Followings = "Followings",
+}
+
+export enum ExportFormat {
+    Json = "Json",
+    Csv = "Csv",
+}
+
+export interface SnapshotUser {
+    user_id: number;
+    username: string;
+    display_name: string;
+    is_terminated: boolean;
+}
+
+export interface FriendsSnapshot {
+    // Unix timestamp in seconds
+    taken_at: number;
+    friends: SnapshotUser[];
+}
+
+export interface FriendsDiff {
+    // Unix timestamp in seconds of the older snapshot
+    since: number;
+    new_friends: SnapshotUser[];
+    unfriended: SnapshotUser[];
+    terminated: SnapshotUser[];
+}
+
 export enum TreatmentType {
     FriendCarousel = "FriendCarousel",
     Carousel = "Carousel",
@@ -130,11 +401,153 @@ export interface GameServer {
 
     player_tokens: string[],
 
-    // pub players: Vec<String>,
+    // Only filled in for friends servers
+    players: GameServerPlayer[],
+
     fps: number,
     ping: number,
 }
 
+export interface GameServerPlayer {
+    player_token: string,
+    user_id: number,
+    username: string,
+    display_name: string,
+}
+
+export enum FillPreference {
+    // Half full servers score the best
+    Balanced = "Balanced",
+    NearlyFull = "NearlyFull",
+    Empty = "Empty",
+}
+
+export interface ServerRankingOptions {
+    // Pages of 100 servers to scan
+    pages?: number,
+    fill_preference?: FillPreference,
+    exclude_server_ids?: string[],
+}
+
+export interface RankedServer {
+    server: GameServer,
+
+    // From 0 (worst) to 1 (best)
+    score: number,
+}
+
+export interface ServerPlayer {
+    player_token: string,
+    headshot_url?: string,
+
+    // Set when the headshot matches one of the friends
+    friend_id?: number,
+}
+
+export interface FindPlayerProgress {
+    user_id: number,
+    place_id: number,
+    pages_scanned: number,
+    servers_scanned: number,
+    max_pages: number,
+
+    // Set while the scan waits out a rate limit
+    rate_limited: boolean,
+}
+
+export interface FoundServer {
+    place_id: number,
+    game_id: string,
+    server: GameServer,
+}
+
+export interface PrivateServer {
+    vip_server_id: number,
+    name: string,
+
+    // Used to join the server
+    access_code: string,
+    owner: PrivateServerUser,
+
+    // Job id of the running server, null if no one is playing
+    game_id?: string,
+    max_players: number,
+    playing: number,
+    player_tokens: string[],
+
+    fps: number,
+    ping: number,
+}
+
+export interface PrivateServerUser {
+    user_id: number,
+    username: string,
+    display_name: string,
+}
+
+export interface PrivateServerOptions {
+    universe_id: number,
+    creation_allowed: boolean,
+
+    // Robux per month, null if creating is not allowed
+    price?: number,
+}
+
+export interface PrivateServerDetails {
+    vip_server_id: number,
+    name: string,
+    universe_id: number,
+    root_place_id: number,
+    active: boolean,
+
+    // Code of the share link, null if it was never generated
+    link_code?: string,
+    link?: string,
+
+    subscription: PrivateServerSubscription,
+    permissions: PrivateServerPermissions,
+}
+
+export interface PrivateServerSubscription {
+    active: boolean,
+    expired: boolean,
+    expiration_date?: string,
+    price?: number,
+    can_renew: boolean,
+}
+
+export interface PrivateServerPermissions {
+    friends_allowed: boolean,
+
+    // Whitelisted users
+    users: PrivateServerUser[],
+}
+
+// Fields which are left out are not changed
+export interface PrivateServerUpdate {
+    name?: string,
+    active?: boolean,
+
+    // Generates a new share link, invalidating the old one
+    new_link_code?: boolean,
+}
+
+export interface PrivateServerPermissionsUpdate {
+    friends_allowed?: boolean,
+    users_to_add?: number[],
+    users_to_remove?: number[],
+}
+
+export interface GameServersOptions {
+    sortOrder?: SortOrder,
+    excludeFullGames?: boolean,
+    limit?: Limit,
+
+    // Loads every page (up to maxPages, at most 50), no cursor is returned then
+    loadAll?: boolean,
+    maxPages?: number,
+}
+
 export enum AvatarType {
     MorphToR6 = "MorphToR6",
     MorphToR15 = "MorphToR15",
@@ -200,6 +613,8 @@ export interface GameDetails extends BaseUniverse {
     created: string;
     updated: string;
 
+    create_vip_servers_allowed: boolean;
+
     /// Avatar type. Possible values are MorphToR6, MorphToR15, and PlayerChoice
     universe_avatar_type: string;
 